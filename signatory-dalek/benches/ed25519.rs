@@ -31,10 +31,45 @@ fn verify_ed25519(c: &mut Criterion) {
     });
 }
 
+fn precomputed_verify_ed25519(c: &mut Criterion) {
+    let verifier = Ed25519Verifier::from(&ed25519::PublicKey::from_bytes(TEST_VECTOR.pk).unwrap());
+    let precomputed = verifier.precompute();
+    let signature = ed25519::Signature::from_bytes(TEST_VECTOR.sig).unwrap();
+
+    c.bench_function("dalek: Ed25519 verifier (precomputed)", move |b| {
+        b.iter(|| precomputed.verify(TEST_VECTOR.msg, &signature).unwrap())
+    });
+}
+
+/// Number of signatures to verify in the `verify_many` benchmarks
+const VERIFY_MANY_COUNT: usize = 64;
+
+fn verify_many_ed25519(c: &mut Criterion) {
+    let verifier = Ed25519Verifier::from(&ed25519::PublicKey::from_bytes(TEST_VECTOR.pk).unwrap());
+    let signature = ed25519::Signature::from_bytes(TEST_VECTOR.sig).unwrap();
+    let items = vec![(TEST_VECTOR.msg, signature); VERIFY_MANY_COUNT];
+
+    c.bench_function("dalek: Ed25519 verifier (64 x verify)", {
+        let verifier = verifier.clone();
+        let items = items.clone();
+        move |b| {
+            b.iter(|| {
+                for (msg, sig) in &items {
+                    verifier.verify(msg, sig).unwrap();
+                }
+            })
+        }
+    });
+
+    c.bench_function("dalek: Ed25519 verifier (verify_many x 64)", move |b| {
+        b.iter(|| verifier.verify_many(&items).unwrap())
+    });
+}
+
 criterion_group! {
     name = ed25519;
     config = Criterion::default();
-    targets = sign_ed25519, verify_ed25519
+    targets = sign_ed25519, verify_ed25519, precomputed_verify_ed25519, verify_many_ed25519
 }
 
 criterion_main!(ed25519);
@@ -0,0 +1,149 @@
+//! Naive aggregation of Ed25519 public keys, as a building block for
+//! multi-party signing schemes
+
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+
+/// Combine several public keys into an aggregate key by summing their Edwards
+/// points, e.g. for naive n-of-n signing where every party signs the same
+/// message and the combined signature is checked against the aggregate key.
+///
+/// Returns `KeyInvalid` if no keys are given or any of them does not decode
+/// to a valid Edwards point.
+///
+/// **This is NOT safe against rogue-key attacks on its own**: a party who
+/// chooses their key after seeing the others' can pick one which cancels
+/// them out, yielding an aggregate key they alone control. Schemes built on
+/// this must have each party prove possession of its secret key (or use a
+/// proper key aggregation scheme such as MuSig).
+pub fn combine_public_keys(keys: &[ed25519::PublicKey]) -> Result<ed25519::PublicKey, Error> {
+    if keys.is_empty() {
+        return Err(Error::new(
+            ErrorKind::KeyInvalid,
+            Some("no public keys to combine"),
+        ));
+    }
+
+    let mut sum = EdwardsPoint::default();
+
+    for key in keys {
+        sum += CompressedEdwardsY(*key.as_bytes())
+            .decompress()
+            .ok_or_else(|| Error::new(ErrorKind::KeyInvalid, Some("invalid public key")))?;
+    }
+
+    Ok(ed25519::PublicKey::new(sum.compress().to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::combine_public_keys;
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use curve25519_dalek::{
+        constants::ED25519_BASEPOINT_TABLE,
+        edwards::{CompressedEdwardsY, EdwardsPoint},
+        scalar::Scalar,
+    };
+    use sha2::{Digest, Sha512};
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed, Verifier,
+    };
+    use std::vec::Vec;
+
+    const MESSAGE: &[u8] = b"message signed by every party";
+
+    /// Secret scalar and nonce prefix of a party, expanded from its seed
+    fn expand(seed: &[u8]) -> (Scalar, [u8; 32]) {
+        let hash = Sha512::digest(seed);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&hash[..32]);
+        scalar[0] &= 0b1111_1000;
+        scalar[31] &= 0b0111_1111;
+        scalar[31] |= 0b0100_0000;
+
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&hash[32..]);
+        (Scalar::from_bits(scalar), prefix)
+    }
+
+    #[test]
+    fn combines_to_point_sum() {
+        let keys = [
+            ed25519::PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap(),
+            ed25519::PublicKey::from_bytes(TEST_VECTORS[1].pk).unwrap(),
+        ];
+        let combined = combine_public_keys(&keys).unwrap();
+
+        let expected = CompressedEdwardsY::from_slice(TEST_VECTORS[0].pk)
+            .decompress()
+            .unwrap()
+            + CompressedEdwardsY::from_slice(TEST_VECTORS[1].pk)
+                .decompress()
+                .unwrap();
+        assert_eq!(combined.as_bytes(), expected.compress().as_bytes());
+    }
+
+    #[test]
+    fn verifies_naively_summed_signature() {
+        let seeds = [TEST_VECTORS[0].sk, TEST_VECTORS[1].sk];
+        let keys = seeds
+            .iter()
+            .map(|seed| {
+                Ed25519Signer::try_from(*seed)
+                    .unwrap()
+                    .public_key()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let combined = combine_public_keys(&keys).unwrap();
+
+        // Each party commits to a nonce, and the nonces are summed
+        let parties = seeds.iter().map(|seed| expand(seed)).collect::<Vec<_>>();
+        let nonces = parties
+            .iter()
+            .map(|(_, prefix)| Scalar::from_hash(Sha512::new().chain(prefix).chain(MESSAGE)))
+            .collect::<Vec<_>>();
+        let r = nonces
+            .iter()
+            .map(|nonce| nonce * &ED25519_BASEPOINT_TABLE)
+            .fold(EdwardsPoint::default(), |acc, point| acc + point)
+            .compress();
+
+        // Each party signs the shared challenge, and the shares are summed
+        let challenge = Scalar::from_hash(
+            Sha512::new()
+                .chain(r.as_bytes())
+                .chain(combined.as_bytes())
+                .chain(MESSAGE),
+        );
+        let s = parties
+            .iter()
+            .zip(&nonces)
+            .fold(Scalar::zero(), |acc, ((scalar, _), nonce)| {
+                acc + nonce + challenge * scalar
+            });
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(r.as_bytes());
+        sig[32..].copy_from_slice(s.as_bytes());
+        let sig = ed25519::Signature::new(sig);
+
+        let verifier = Ed25519Verifier::from(&combined);
+        assert!(verifier.verify(MESSAGE, &sig).is_ok());
+
+        let single = Ed25519Verifier::from(&keys[0]);
+        assert!(single.verify(MESSAGE, &sig).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_key_set() {
+        let err = combine_public_keys(&[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+}
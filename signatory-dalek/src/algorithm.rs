@@ -0,0 +1,78 @@
+//! Identifiers for the signature algorithms supported by this crate
+
+use core::fmt::{self, Display};
+
+/// Object identifier for Ed25519 (RFC 8410 Section 3) in dotted-decimal form
+pub const ED25519_OID: &str = "1.3.101.112";
+
+/// Signature algorithms supported by this crate, for use by protocols which
+/// negotiate or advertise them
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Algorithm {
+    /// Ed25519 (RFC 8032 Section 5.1)
+    Ed25519,
+}
+
+impl Algorithm {
+    /// Human-readable name of this algorithm
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Ed25519 => "Ed25519",
+        }
+    }
+
+    /// Object identifier for this algorithm in dotted-decimal form
+    pub fn oid(self) -> &'static str {
+        match self {
+            Algorithm::Ed25519 => ED25519_OID,
+        }
+    }
+
+    /// DER encoding of the object identifier for this algorithm (i.e. the
+    /// contents of an `OBJECT IDENTIFIER`, without its tag and length)
+    #[cfg(any(feature = "pkcs8", feature = "spki"))]
+    pub(crate) fn oid_der(self) -> &'static [u8] {
+        match self {
+            // 1.3 => 40 * 1 + 3, followed by 101 and 112 (all < 128)
+            Algorithm::Ed25519 => &[0x2b, 0x65, 0x70],
+        }
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, ED25519_OID};
+    use std::string::ToString;
+    #[cfg(any(feature = "pkcs8", feature = "spki"))]
+    use std::vec::Vec;
+
+    /// Encode a dotted-decimal OID whose arcs (after the first two) are all
+    /// less than 128 as DER
+    #[cfg(any(feature = "pkcs8", feature = "spki"))]
+    fn encode_oid(oid: &str) -> Vec<u8> {
+        let arcs: Vec<u8> = oid.split('.').map(|arc| arc.parse().unwrap()).collect();
+        let mut der = vec![arcs[0] * 40 + arcs[1]];
+        der.extend_from_slice(&arcs[2..]);
+        der
+    }
+
+    #[test]
+    fn ed25519_identifiers() {
+        assert_eq!(ED25519_OID, "1.3.101.112");
+        assert_eq!(Algorithm::Ed25519.oid(), ED25519_OID);
+        assert_eq!(Algorithm::Ed25519.name(), "Ed25519");
+        assert_eq!(Algorithm::Ed25519.to_string(), "Ed25519");
+    }
+
+    #[cfg(any(feature = "pkcs8", feature = "spki"))]
+    #[test]
+    fn oid_der_matches_dotted_oid() {
+        assert_eq!(Algorithm::Ed25519.oid_der(), &encode_oid(ED25519_OID)[..]);
+    }
+}
@@ -0,0 +1,105 @@
+//! Base64 encoding of Ed25519 public keys into caller-provided buffers,
+//! usable without `alloc`
+
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+use subtle_encoding::{Base64, Encoding};
+
+use crate::{decode_public_key, Ed25519Verifier};
+
+/// Size of a standard (padded) base64-encoded Ed25519 public key in bytes
+pub const PUBLIC_KEY_BASE64_SIZE: usize = 44;
+
+impl Ed25519Verifier {
+    /// Write this verifier's public key as standard (padded) base64 into the
+    /// given buffer, returning the number of bytes written (always
+    /// `PUBLIC_KEY_BASE64_SIZE`), or `Io` if the buffer is too small
+    pub fn encode_base64(&self, out: &mut [u8]) -> Result<usize, Error> {
+        if out.len() < PUBLIC_KEY_BASE64_SIZE {
+            return Err(Error::new(
+                ErrorKind::Io,
+                Some("output buffer too small for base64 public key"),
+            ));
+        }
+
+        Ok(Base64::default()
+            .encode_to_slice(self.0.as_bytes(), &mut out[..PUBLIC_KEY_BASE64_SIZE])
+            .unwrap())
+    }
+
+    /// Create a new Ed25519 verifier from a standard (padded) base64-encoded
+    /// public key, returning `ParseError` if it is not valid base64 of a
+    /// 32-byte value, and `KeyInvalid` if the public key is not a valid
+    /// Edwards point
+    pub fn decode_base64(encoded: &[u8]) -> Result<Self, Error> {
+        let mut bytes = [0u8; ed25519::PUBLIC_KEY_SIZE];
+
+        if encoded.len() != PUBLIC_KEY_BASE64_SIZE
+            || Base64::default().decode_to_slice(encoded, &mut bytes) != Ok(bytes.len())
+        {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("expected base64-encoded 32-byte public key"),
+            ));
+        }
+
+        decode_public_key(&bytes).map(Ed25519Verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PUBLIC_KEY_BASE64_SIZE;
+    use crate::Ed25519Verifier;
+    use signatory::{ed25519::TEST_VECTORS, error::ErrorKind};
+
+    /// RFC 8032 test 1 public key as standard base64
+    const BASE64_PUBLIC_KEY: &[u8] = b"11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+
+    #[test]
+    fn encode_exact_size_buffer() {
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let mut out = [0u8; PUBLIC_KEY_BASE64_SIZE];
+
+        assert_eq!(verifier.encode_base64(&mut out).unwrap(), out.len());
+        assert_eq!(&out[..], BASE64_PUBLIC_KEY);
+    }
+
+    #[test]
+    fn encode_oversized_buffer() {
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let mut out = [0u8; 64];
+
+        let len = verifier.encode_base64(&mut out).unwrap();
+        assert_eq!(&out[..len], BASE64_PUBLIC_KEY);
+        assert!(out[len..].iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn encode_undersized_buffer() {
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let mut out = [0u8; PUBLIC_KEY_BASE64_SIZE - 1];
+
+        let err = verifier.encode_base64(&mut out).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Io);
+    }
+
+    #[test]
+    fn decode_round_trip() {
+        let verifier = Ed25519Verifier::decode_base64(BASE64_PUBLIC_KEY).unwrap();
+        assert_eq!(verifier.as_ref(), TEST_VECTORS[0].pk);
+    }
+
+    #[test]
+    fn decode_rejects_malformed() {
+        for encoded in &[
+            &BASE64_PUBLIC_KEY[..40],
+            &b"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo="[..],
+        ] {
+            let err = Ed25519Verifier::decode_base64(encoded).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
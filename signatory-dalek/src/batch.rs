@@ -0,0 +1,292 @@
+//! Batch verification of Ed25519 signatures
+
+use digest::Digest;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    generic_array::typenum::U64,
+};
+use std::vec::Vec;
+
+use crate::{decode_public_key, decode_signature};
+
+/// Number of signatures verified by each thread in `verify_batch_par`
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_SIZE: usize = 64;
+
+/// Verify a batch of Ed25519 signatures over the given messages under the
+/// given public keys, which is considerably faster than verifying each of
+/// them individually.
+///
+/// Returns `ParseError` if the slices are not all the same length, and
+/// `SignatureInvalid` if any of the signatures fail to verify.
+pub fn verify_batch(
+    messages: &[&[u8]],
+    signatures: &[ed25519::Signature],
+    public_keys: &[ed25519::PublicKey],
+) -> Result<(), Error> {
+    if messages.len() != signatures.len() || messages.len() != public_keys.len() {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("the number of messages, signatures, and public keys must be equal"),
+        ));
+    }
+
+    let dalek_signatures = signatures
+        .iter()
+        .map(decode_signature)
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let dalek_public_keys = public_keys
+        .iter()
+        .map(|pk| decode_public_key(pk.as_ref()))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    ed25519_dalek::verify_batch(messages, &dalek_signatures, &dalek_public_keys)
+        .map_err(|_| ErrorKind::SignatureInvalid.into())
+}
+
+/// Verify a batch of Ed25519 signatures as in `verify_batch`, splitting the
+/// batch into chunks which are verified in parallel on the rayon thread pool.
+///
+/// Returns `ParseError` if the slices are not all the same length, and
+/// `SignatureInvalid` if any of the signatures fail to verify.
+#[cfg(feature = "rayon")]
+pub fn verify_batch_par(
+    messages: &[&[u8]],
+    signatures: &[ed25519::Signature],
+    public_keys: &[ed25519::PublicKey],
+) -> Result<(), Error> {
+    if messages.len() != signatures.len() || messages.len() != public_keys.len() {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("the number of messages, signatures, and public keys must be equal"),
+        ));
+    }
+
+    messages
+        .par_chunks(PAR_CHUNK_SIZE)
+        .zip(signatures.par_chunks(PAR_CHUNK_SIZE))
+        .zip(public_keys.par_chunks(PAR_CHUNK_SIZE))
+        .try_for_each(|((messages, signatures), public_keys)| {
+            verify_batch(messages, signatures, public_keys)
+        })
+}
+
+/// Verify a batch of Ed25519ph (i.e. pre-hashed) signatures over the given
+/// digests under the given public keys, without a context string.
+///
+/// ed25519-dalek has no batch API for Ed25519ph, so the signatures are
+/// verified one at a time, stopping at the first which fails to verify.
+///
+/// Returns `ParseError` if the slices are not all the same length, and
+/// `SignatureInvalid` if any of the signatures fail to verify.
+pub fn verify_batch_prehashed<D>(
+    digests: &[D],
+    signatures: &[ed25519::Signature],
+    public_keys: &[ed25519::PublicKey],
+) -> Result<(), Error>
+where
+    D: Digest<OutputSize = U64> + Clone + Default,
+{
+    if digests.len() != signatures.len() || digests.len() != public_keys.len() {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("the number of digests, signatures, and public keys must be equal"),
+        ));
+    }
+
+    for ((digest, sig), pk) in digests.iter().zip(signatures).zip(public_keys) {
+        let dalek_sig = decode_signature(sig)?;
+
+        decode_public_key(pk.as_ref())?
+            .verify_prehashed(digest.clone(), None, &dalek_sig)
+            .map_err(|_| Error::from(ErrorKind::SignatureInvalid))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify_batch, verify_batch_prehashed};
+    use crate::Ed25519PhSigner;
+    use sha2::{Digest, Sha512};
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        DigestSigner, PublicKeyed, Signature,
+    };
+    use std::vec::Vec;
+
+    /// Split the RFC 8032 test vectors into messages, signatures, and public keys
+    fn test_vector_batch() -> (
+        Vec<&'static [u8]>,
+        Vec<ed25519::Signature>,
+        Vec<ed25519::PublicKey>,
+    ) {
+        let messages = TEST_VECTORS.iter().map(|vector| vector.msg).collect();
+        let signatures = TEST_VECTORS
+            .iter()
+            .map(|vector| ed25519::Signature::from_bytes(vector.sig).unwrap())
+            .collect();
+        let public_keys = TEST_VECTORS
+            .iter()
+            .map(|vector| ed25519::PublicKey::from_bytes(vector.pk).unwrap())
+            .collect();
+
+        (messages, signatures, public_keys)
+    }
+
+    /// Sign SHA-512 digests of the RFC 8032 test vector messages using
+    /// Ed25519ph, returning the digests, signatures, and public keys
+    fn prehashed_test_vector_batch() -> (
+        Vec<Sha512>,
+        Vec<ed25519::Signature>,
+        Vec<ed25519::PublicKey>,
+    ) {
+        let digests: Vec<_> = TEST_VECTORS
+            .iter()
+            .map(|vector| Sha512::new().chain(vector.msg))
+            .collect();
+
+        let signers: Vec<_> = TEST_VECTORS
+            .iter()
+            .map(|vector| Ed25519PhSigner::from(&ed25519::Seed::from_bytes(vector.sk).unwrap()))
+            .collect();
+
+        let signatures = signers
+            .iter()
+            .zip(&digests)
+            .map(|(signer, digest)| signer.sign(digest.clone()).unwrap())
+            .collect();
+
+        let public_keys = signers
+            .iter()
+            .map(|signer| signer.public_key().unwrap())
+            .collect();
+
+        (digests, signatures, public_keys)
+    }
+
+    #[test]
+    fn verifies_rfc8032_test_vectors() {
+        let (messages, signatures, public_keys) = test_vector_batch();
+        assert!(verify_batch(&messages, &signatures, &public_keys).is_ok());
+    }
+
+    #[test]
+    fn rejects_batch_with_tweaked_signature() {
+        let (messages, mut signatures, public_keys) = test_vector_batch();
+        signatures[2].0[0] ^= 0x42;
+
+        let result = verify_batch(&messages, &signatures, &public_keys);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_mismatched_lengths() {
+        let (messages, signatures, public_keys) = test_vector_batch();
+
+        let result = verify_batch(&messages[1..], &signatures, &public_keys);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+
+        let result = verify_batch(&messages, &signatures, &public_keys[1..]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn verifies_prehashed_batch() {
+        let (digests, signatures, public_keys) = prehashed_test_vector_batch();
+        assert!(verify_batch_prehashed(&digests, &signatures, &public_keys).is_ok());
+    }
+
+    #[test]
+    fn rejects_prehashed_batch_with_tampered_digest() {
+        let (mut digests, signatures, public_keys) = prehashed_test_vector_batch();
+        digests[1].input(b"tampered");
+
+        let result = verify_batch_prehashed(&digests, &signatures, &public_keys);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_prehashed_batch_with_mismatched_lengths() {
+        let (digests, signatures, public_keys) = prehashed_test_vector_batch();
+
+        let result = verify_batch_prehashed(&digests[1..], &signatures, &public_keys);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+
+        let result = verify_batch_prehashed(&digests, &signatures[1..], &public_keys);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+    }
+
+    #[cfg(feature = "rayon")]
+    mod par {
+        use super::super::{verify_batch_par, PAR_CHUNK_SIZE};
+        use crate::Ed25519Signer;
+        use signatory::{ed25519, error::ErrorKind, PublicKeyed, Signer};
+        use std::vec::Vec;
+
+        /// Number of signatures in the test batch, spanning several chunks
+        const BATCH_SIZE: usize = 2 * PAR_CHUNK_SIZE + 3;
+
+        /// Sign `BATCH_SIZE` distinct messages, returning the messages,
+        /// signatures, and public keys
+        fn par_test_batch() -> (
+            Vec<[u8; 8]>,
+            Vec<ed25519::Signature>,
+            Vec<ed25519::PublicKey>,
+        ) {
+            let signer = Ed25519Signer::from(&ed25519::Seed::new([0x42; 32]));
+            let public_key = signer.public_key().unwrap();
+            let messages: Vec<_> = (0..BATCH_SIZE as u64).map(u64::to_le_bytes).collect();
+            let signatures = messages
+                .iter()
+                .map(|msg| signer.sign(msg).unwrap())
+                .collect();
+
+            (messages, signatures, vec![public_key; BATCH_SIZE])
+        }
+
+        #[test]
+        fn verifies_parallel_batch() {
+            let (messages, signatures, public_keys) = par_test_batch();
+            let messages: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+            assert!(verify_batch_par(&messages, &signatures, &public_keys).is_ok());
+        }
+
+        #[test]
+        fn rejects_parallel_batch_with_tweaked_signature() {
+            let (messages, signatures, public_keys) = par_test_batch();
+            let messages: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+
+            // A bad signature must be caught wherever it lies relative to the
+            // chunk boundaries
+            for &index in &[
+                0,
+                PAR_CHUNK_SIZE - 1,
+                PAR_CHUNK_SIZE,
+                PAR_CHUNK_SIZE + 1,
+                BATCH_SIZE - 1,
+            ] {
+                let mut signatures = signatures.clone();
+                signatures[index].0[0] ^= 0x42;
+
+                let result = verify_batch_par(&messages, &signatures, &public_keys);
+                assert_eq!(result.err().unwrap().kind(), ErrorKind::SignatureInvalid);
+            }
+        }
+
+        #[test]
+        fn rejects_parallel_batch_with_mismatched_lengths() {
+            let (messages, signatures, public_keys) = par_test_batch();
+            let messages: Vec<&[u8]> = messages.iter().map(|msg| &msg[..]).collect();
+
+            let result = verify_batch_par(&messages[1..], &signatures, &public_keys);
+            assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+        }
+    }
+}
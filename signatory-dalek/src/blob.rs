@@ -0,0 +1,102 @@
+//! Self-verifying signed message blobs: `public_key || signature || message`
+
+use alloc::vec::Vec;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Signature, Verifier,
+};
+
+use crate::{Ed25519Signer, Ed25519Verifier, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+
+/// Size of the `public_key || signature` header preceding the message in a
+/// signed blob
+pub const BLOB_HEADER_SIZE: usize = PUBLIC_KEY_LENGTH + SIGNATURE_LENGTH;
+
+impl Ed25519Signer {
+    /// Sign a message, returning a self-contained
+    /// `public_key || signature || message` blob which can be checked with
+    /// `verify_blob`
+    pub fn sign_blob(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        let (public_key, signature) = self.sign_with_public_key(msg)?;
+
+        let mut blob = Vec::with_capacity(BLOB_HEADER_SIZE + msg.len());
+        blob.extend_from_slice(public_key.as_bytes());
+        blob.extend_from_slice(signature.as_bytes());
+        blob.extend_from_slice(msg);
+        Ok(blob)
+    }
+}
+
+/// Verify a blob produced by `Ed25519Signer::sign_blob` against the public
+/// key it carries, returning the signed message on success.
+///
+/// Returns `ParseError` if the blob is shorter than `BLOB_HEADER_SIZE`,
+/// `KeyInvalid` if the public key is malformed, and `SignatureInvalid` if the
+/// signature does not verify.
+///
+/// The blob only proves that the message was signed by *some* key: callers
+/// must still check that the embedded public key (the first
+/// `PUBLIC_KEY_LENGTH` bytes of the blob) is one they trust.
+pub fn verify_blob(blob: &[u8]) -> Result<Vec<u8>, Error> {
+    let (public_key, signature, msg) = split_blob(blob)?;
+    Ed25519Verifier::from_bytes(public_key)?.verify(msg, &signature)?;
+    Ok(msg.to_vec())
+}
+
+/// Split a blob into its public key, signature, and message
+fn split_blob(blob: &[u8]) -> Result<(&[u8], ed25519::Signature, &[u8]), Error> {
+    if blob.len() < BLOB_HEADER_SIZE {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("signed blob is shorter than its 96-byte header"),
+        ));
+    }
+
+    let (public_key, rest) = blob.split_at(PUBLIC_KEY_LENGTH);
+    let (signature, msg) = rest.split_at(SIGNATURE_LENGTH);
+    Ok((public_key, ed25519::Signature::from_bytes(signature)?, msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify_blob, BLOB_HEADER_SIZE};
+    use crate::Ed25519Signer;
+    use core::convert::TryFrom;
+    use signatory::{ed25519::TEST_VECTORS, error::ErrorKind};
+
+    #[test]
+    fn round_trip() {
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let blob = signer.sign_blob(vector.msg).unwrap();
+
+            assert_eq!(blob.len(), BLOB_HEADER_SIZE + vector.msg.len());
+            assert_eq!(&blob[..32], vector.pk);
+            assert_eq!(&blob[32..BLOB_HEADER_SIZE], vector.sig);
+            assert_eq!(verify_blob(&blob).unwrap(), vector.msg);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_blob() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let blob = signer.sign_blob(b"").unwrap();
+        assert_eq!(blob.len(), BLOB_HEADER_SIZE);
+
+        for len in 0..BLOB_HEADER_SIZE {
+            let err = verify_blob(&blob[..len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn rejects_tampered_message() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[2].sk).unwrap();
+        let mut blob = signer.sign_blob(TEST_VECTORS[2].msg).unwrap();
+        *blob.last_mut().unwrap() ^= 1;
+
+        let err = verify_blob(&blob).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+}
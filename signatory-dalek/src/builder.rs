@@ -0,0 +1,168 @@
+//! Builder consolidating the options for constructing Ed25519 signers
+
+use core::convert::TryFrom;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    PublicKeyed,
+};
+
+use crate::{Context, Ed25519PhSigner, Ed25519Signer};
+
+/// Builder for Ed25519 and Ed25519ph signers, providing a single entry point
+/// for configuring the key and signature scheme.
+///
+/// A seed must always be given. Without `prehash(true)` the builder produces
+/// an `Ed25519Signer`; with it, an `Ed25519PhSigner` bound to the configured
+/// context (if any).
+#[derive(Default)]
+pub struct Ed25519SignerBuilder {
+    /// Seed to derive the signer's key from
+    seed: Option<ed25519::Seed>,
+
+    /// Ed25519ph context string
+    context: Option<Context>,
+
+    /// Whether to build an Ed25519ph signer
+    prehash: bool,
+}
+
+impl Ed25519SignerBuilder {
+    /// Create a new builder with no seed, no context, and prehashing disabled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the seed to derive the signer's key from
+    pub fn seed(mut self, seed: ed25519::Seed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the context string for Ed25519ph signatures. Pure Ed25519 does not
+    /// support contexts, so this requires `prehash(true)`.
+    pub fn context(mut self, context: Context) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Set whether to build an Ed25519ph (i.e. pre-hashed) signer
+    pub fn prehash(mut self, prehash: bool) -> Self {
+        self.prehash = prehash;
+        self
+    }
+
+    /// Build the configured signer, returning `KeyInvalid` if no seed was
+    /// given (or ed25519-dalek rejects it), and `ProviderError` if a context
+    /// was given for a pure Ed25519 signer
+    pub fn build(self) -> Result<BuiltSigner, Error> {
+        let seed = self
+            .seed
+            .as_ref()
+            .ok_or_else(|| Error::new(ErrorKind::KeyInvalid, Some("no seed given")))?;
+
+        if !self.prehash {
+            if self.context.is_some() {
+                return Err(Error::new(
+                    ErrorKind::ProviderError,
+                    Some("contexts are only supported for Ed25519ph signers"),
+                ));
+            }
+
+            return Ed25519Signer::from_seed(seed).map(BuiltSigner::Ed25519);
+        }
+
+        let signer = match self.context {
+            Some(context) => Ed25519PhSigner::new_with_context(seed, context)?,
+            None => Ed25519PhSigner::try_from(seed.as_secret_slice())?,
+        };
+
+        Ok(BuiltSigner::Ed25519Ph(signer))
+    }
+}
+
+/// Signer produced by `Ed25519SignerBuilder`
+#[derive(Clone, Debug)]
+pub enum BuiltSigner {
+    /// Pure Ed25519 signer
+    Ed25519(Ed25519Signer),
+
+    /// Ed25519ph (i.e. pre-hashed) signer
+    Ed25519Ph(Ed25519PhSigner),
+}
+
+impl PublicKeyed<ed25519::PublicKey> for BuiltSigner {
+    fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
+        match self {
+            BuiltSigner::Ed25519(signer) => signer.public_key(),
+            BuiltSigner::Ed25519Ph(signer) => signer.public_key(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuiltSigner, Ed25519SignerBuilder};
+    use crate::{Context, Ed25519PhVerifier};
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed, Signer,
+    };
+
+    fn seed() -> Seed {
+        Seed::from_bytes(TEST_VECTORS[0].sk).unwrap()
+    }
+
+    #[test]
+    fn builds_plain_signer() {
+        let signer = Ed25519SignerBuilder::new().seed(seed()).build().unwrap();
+
+        match signer {
+            BuiltSigner::Ed25519(signer) => {
+                let sig = signer.sign(TEST_VECTORS[0].msg).unwrap();
+                assert_eq!(sig.as_bytes(), TEST_VECTORS[0].sig);
+            }
+            other => panic!("expected Ed25519 signer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn builds_prehash_signer_with_context() {
+        let context = Context::new(b"signatory-test").unwrap();
+        let signer = Ed25519SignerBuilder::new()
+            .seed(seed())
+            .context(context)
+            .prehash(true)
+            .build()
+            .unwrap();
+
+        let verifier =
+            Ed25519PhVerifier::from_public_key_with_context(&signer.public_key().unwrap(), context)
+                .unwrap();
+
+        match signer {
+            BuiltSigner::Ed25519Ph(signer) => {
+                let sig = signer.sign_message(b"message").unwrap();
+                assert!(verifier.verify_message(b"message", &sig).is_ok());
+            }
+            other => panic!("expected Ed25519ph signer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_missing_seed() {
+        let err = Ed25519SignerBuilder::new().build().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn rejects_context_without_prehash() {
+        let err = Ed25519SignerBuilder::new()
+            .seed(seed())
+            .context(Context::new(b"signatory-test").unwrap())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+    }
+}
@@ -0,0 +1,87 @@
+//! Interoperability with the RustCrypto `ed25519` and `signature` crates
+//!
+//! `Ed25519Signer` and `Ed25519Verifier` implement the `signature` crate's
+//! `Signer` and `Verifier` traits for `ed25519::Signature` from the `ed25519`
+//! crate, allowing them to be used with crates built against those traits.
+//! Both crates are re-exported from this crate (the former as
+//! `ed25519_compat`).
+
+use signatory::{ed25519, Verifier};
+
+use crate::{Ed25519Signer, Ed25519Verifier};
+
+/// Convert an `ed25519` crate signature into a Signatory signature
+pub fn from_compat_signature(sig: &ed25519_compat::Signature) -> ed25519::Signature {
+    ed25519::Signature::new(sig.to_bytes())
+}
+
+/// Convert a Signatory signature into an `ed25519` crate signature
+pub fn to_compat_signature(sig: &ed25519::Signature) -> ed25519_compat::Signature {
+    ed25519_compat::Signature::from_bytes(&sig.0)
+}
+
+impl signature::Signer<ed25519_compat::Signature> for Ed25519Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<ed25519_compat::Signature, signature::Error> {
+        self.sign_message(msg)
+            .map(|sig| to_compat_signature(&sig))
+            .map_err(|_| signature::Error::new())
+    }
+}
+
+impl signature::Verifier<ed25519_compat::Signature> for Ed25519Verifier {
+    fn verify(&self, msg: &[u8], sig: &ed25519_compat::Signature) -> Result<(), signature::Error> {
+        Verifier::verify(self, msg, &from_compat_signature(sig))
+            .map_err(|_| signature::Error::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_compat_signature, to_compat_signature};
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use ed25519_dalek_v2::Signer as _;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        Signature,
+    };
+
+    #[test]
+    fn verifies_rustcrypto_signature() {
+        let vector = &TEST_VECTORS[1];
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(vector.sk);
+
+        let external_signer = ed25519_dalek_v2::SigningKey::from_bytes(&seed);
+        let sig: ed25519_compat::Signature = external_signer.sign(vector.msg);
+
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        assert!(signature::Verifier::verify(&verifier, vector.msg, &sig).is_ok());
+        assert!(signature::Verifier::verify(&verifier, b"tampered", &sig).is_err());
+    }
+
+    #[test]
+    fn signs_with_rustcrypto_signer_trait() {
+        let vector = &TEST_VECTORS[2];
+        let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+        let sig: ed25519_compat::Signature = signature::Signer::sign(&signer, vector.msg);
+
+        assert_eq!(&sig.to_bytes()[..], vector.sig);
+
+        let external_verifier =
+            ed25519_dalek_v2::VerifyingKey::from_bytes(&public_key_array(vector.pk)).unwrap();
+        assert!(external_verifier.verify_strict(vector.msg, &sig).is_ok());
+    }
+
+    #[test]
+    fn signature_conversion_round_trip() {
+        let sig = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        assert_eq!(from_compat_signature(&to_compat_signature(&sig)), sig);
+    }
+
+    fn public_key_array(bytes: &[u8]) -> [u8; 32] {
+        let mut pk = [0u8; 32];
+        pk.copy_from_slice(bytes);
+        pk
+    }
+}
@@ -0,0 +1,74 @@
+//! Ed25519ph context strings for domain separation (RFC 8032 Section 5.1)
+
+use signatory::error::{Error, ErrorKind};
+
+/// Maximum size of an Ed25519ph context string in bytes (RFC 8032 Section 5.1)
+pub const MAX_CONTEXT_SIZE: usize = 255;
+
+/// Ed25519ph context string, validated to be at most `MAX_CONTEXT_SIZE` bytes.
+///
+/// Signatures are bound to the context they were produced under, and only
+/// verify under the same context. Contexts are cheap to copy, so a single
+/// `Context` can be shared by many signers and verifiers.
+///
+/// Context strings must be `'static` as ed25519-dalek's Ed25519ph signing API
+/// requires them to be: use `Ed25519PhSigner::sign_with_context` to sign under
+/// a context built at runtime.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Context(&'static [u8]);
+
+impl Context {
+    /// Create a new context string, returning `ContextInvalid` if it is
+    /// longer than `MAX_CONTEXT_SIZE`
+    pub fn new(bytes: &'static [u8]) -> Result<Self, Error> {
+        check_size(bytes)?;
+        Ok(Context(bytes))
+    }
+
+    /// Borrow the bytes of this context string
+    pub fn as_bytes(&self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Context {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+/// Return `ContextInvalid` if the given context string is longer than
+/// `MAX_CONTEXT_SIZE`
+pub(crate) fn check_size(bytes: &[u8]) -> Result<(), Error> {
+    if bytes.len() > MAX_CONTEXT_SIZE {
+        return Err(Error::new(
+            ErrorKind::ContextInvalid,
+            Some("context must be at most 255 bytes"),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, MAX_CONTEXT_SIZE};
+    use signatory::error::ErrorKind;
+
+    #[test]
+    fn empty_context() {
+        assert_eq!(Context::new(b"").unwrap().as_bytes(), b"");
+    }
+
+    #[test]
+    fn max_size_context() {
+        const CONTEXT: &[u8] = &[0x42; MAX_CONTEXT_SIZE];
+        assert_eq!(Context::new(CONTEXT).unwrap().as_bytes(), CONTEXT);
+    }
+
+    #[test]
+    fn oversized_context() {
+        let err = Context::new(&[0x42; MAX_CONTEXT_SIZE + 1]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ContextInvalid);
+    }
+}
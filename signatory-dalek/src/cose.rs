@@ -0,0 +1,406 @@
+//! COSE_Key (RFC 9052/9053) support for Ed25519 keys, as used by WebAuthn
+//! and CTAP
+//!
+//! Only the subset of CBOR needed for COSE_Key maps is supported: integer
+//! and byte/text string values with lengths which fit in a single byte.
+
+use alloc::vec::Vec;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    PublicKeyed,
+};
+
+use crate::{decode_public_key, Ed25519Signer, Ed25519Verifier};
+
+/// COSE_Key map labels (RFC 9052 Section 7.1, RFC 9053 Section 7.2)
+const LABEL_KTY: i64 = 1;
+const LABEL_ALG: i64 = 3;
+const LABEL_CRV: i64 = -1;
+const LABEL_X: i64 = -2;
+const LABEL_D: i64 = -4;
+
+/// COSE key type for octet key pairs
+const KTY_OKP: i64 = 1;
+
+/// COSE algorithm identifier for EdDSA
+const ALG_EDDSA: i64 = -8;
+
+/// COSE elliptic curve identifier for Ed25519
+const CRV_ED25519: i64 = 6;
+
+/// CBOR major types
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_MAP: u8 = 5;
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from the `x` parameter of a CBOR-encoded
+    /// COSE_Key.
+    ///
+    /// Returns `ParseError` if the key is malformed, its `kty`/`crv` are not
+    /// `OKP`/`Ed25519`, or it specifies an algorithm other than `EdDSA`, and
+    /// `KeyInvalid` if the public key is not a valid Edwards point.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        let key = CoseKey::parse(bytes)?;
+        let x = key
+            .x
+            .ok_or_else(|| parse_error("missing COSE_Key x parameter"))?;
+        decode_public_key(x).map(Ed25519Verifier)
+    }
+
+    /// Serialize this verifier's public key as a CBOR-encoded COSE_Key
+    pub fn to_cose_key(&self) -> Vec<u8> {
+        encode_cose_key(self.0.as_bytes(), None)
+    }
+}
+
+impl Ed25519Signer {
+    /// Create a new Ed25519 signer from the `d` parameter of a CBOR-encoded
+    /// COSE_Key.
+    ///
+    /// Returns `ParseError` under the same conditions as
+    /// `Ed25519Verifier::from_cose_key`, and `KeyInvalid` if the key contains
+    /// an `x` parameter which does not correspond to `d`.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<Self, Error> {
+        let key = CoseKey::parse(bytes)?;
+        let d = key
+            .d
+            .ok_or_else(|| parse_error("missing COSE_Key d parameter"))?;
+
+        let seed = ed25519::Seed::from_bytes(d)
+            .map_err(|_| parse_error("expected 32-byte COSE_Key d parameter"))?;
+        let signer = Self::from_seed(&seed)?;
+
+        if let Some(x) = key.x {
+            if x != signer.public_key()?.as_bytes() {
+                return Err(Error::new(
+                    ErrorKind::KeyInvalid,
+                    Some("public key does not match seed"),
+                ));
+            }
+        }
+
+        Ok(signer)
+    }
+
+    /// Serialize this signer as a CBOR-encoded COSE_Key containing both its
+    /// public key and seed.
+    ///
+    /// The returned bytes contain secret key material: the caller is
+    /// responsible for zeroizing them once they are no longer needed.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, Error> {
+        let seed = self.to_seed()?;
+        Ok(encode_cose_key(
+            self.public.as_bytes(),
+            Some(seed.as_secret_slice()),
+        ))
+    }
+}
+
+/// Parameters of an Ed25519 COSE_Key
+#[derive(Default)]
+struct CoseKey<'a> {
+    /// Public key
+    x: Option<&'a [u8]>,
+
+    /// Private key (i.e. seed)
+    d: Option<&'a [u8]>,
+}
+
+impl<'a> CoseKey<'a> {
+    /// Parse a CBOR-encoded COSE_Key, ensuring it is an Ed25519 key
+    fn parse(mut input: &'a [u8]) -> Result<Self, Error> {
+        let (major, len) = read_header(&mut input)?;
+        if major != MAJOR_MAP {
+            return Err(parse_error("COSE_Key is not a CBOR map"));
+        }
+
+        let mut key = CoseKey::default();
+        let mut kty = None;
+        let mut crv = None;
+        let mut alg = None;
+
+        for _ in 0..len {
+            let label = read_int(&mut input)?;
+            let value = read_value(&mut input)?;
+
+            let slot = match label {
+                LABEL_KTY => set_once(&mut kty, value.as_int()?),
+                LABEL_ALG => set_once(&mut alg, value.as_int()?),
+                LABEL_CRV => set_once(&mut crv, value.as_int()?),
+                LABEL_X => set_once(&mut key.x, value.as_bytes()?),
+                LABEL_D => set_once(&mut key.d, value.as_bytes()?),
+                _ => Ok(()),
+            };
+
+            slot?;
+        }
+
+        if !input.is_empty() {
+            return Err(parse_error("unexpected trailing CBOR data"));
+        }
+
+        if kty != Some(KTY_OKP) {
+            return Err(parse_error("COSE_Key kty is not OKP"));
+        }
+
+        if crv != Some(CRV_ED25519) {
+            return Err(parse_error("COSE_Key crv is not Ed25519"));
+        }
+
+        if alg.is_some() && alg != Some(ALG_EDDSA) {
+            return Err(parse_error("COSE_Key alg is not EdDSA"));
+        }
+
+        Ok(key)
+    }
+}
+
+/// CBOR values which may appear in a COSE_Key
+enum Value<'a> {
+    Int(i64),
+    Bytes(&'a [u8]),
+    Text,
+}
+
+impl<'a> Value<'a> {
+    fn as_int(&self) -> Result<i64, Error> {
+        match self {
+            Value::Int(value) => Ok(*value),
+            _ => Err(parse_error("expected CBOR integer")),
+        }
+    }
+
+    fn as_bytes(&self) -> Result<&'a [u8], Error> {
+        match self {
+            Value::Bytes(value) => Ok(value),
+            _ => Err(parse_error("expected CBOR byte string")),
+        }
+    }
+}
+
+/// Set the given COSE_Key parameter, rejecting duplicates
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Result<(), Error> {
+    if slot.is_some() {
+        return Err(parse_error("duplicate COSE_Key parameter"));
+    }
+
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Read a CBOR header, returning its major type and argument
+fn read_header(input: &mut &[u8]) -> Result<(u8, u64), Error> {
+    let (&initial, rest) = input
+        .split_first()
+        .ok_or_else(|| parse_error("truncated CBOR data"))?;
+
+    let major = initial >> 5;
+    let (argument, rest) = match initial & 0x1f {
+        info @ 0..=23 => (u64::from(info), rest),
+        24 => match rest.split_first() {
+            // Arguments must be minimally encoded
+            Some((&argument, rest)) if argument >= 24 => (u64::from(argument), rest),
+            _ => return Err(parse_error("malformed CBOR header")),
+        },
+        _ => return Err(parse_error("unsupported CBOR header")),
+    };
+
+    *input = rest;
+    Ok((major, argument))
+}
+
+/// Read a CBOR integer
+fn read_int(input: &mut &[u8]) -> Result<i64, Error> {
+    read_value(input)?.as_int()
+}
+
+/// Read a CBOR value
+fn read_value<'a>(input: &mut &'a [u8]) -> Result<Value<'a>, Error> {
+    let (major, argument) = read_header(input)?;
+
+    match major {
+        MAJOR_UNSIGNED => Ok(Value::Int(argument as i64)),
+        MAJOR_NEGATIVE => Ok(Value::Int(-1 - argument as i64)),
+        MAJOR_BYTES | MAJOR_TEXT => {
+            let len = argument as usize;
+            if input.len() < len {
+                return Err(parse_error("truncated CBOR data"));
+            }
+
+            let (value, rest) = input.split_at(len);
+            *input = rest;
+
+            if major == MAJOR_BYTES {
+                Ok(Value::Bytes(value))
+            } else {
+                Ok(Value::Text)
+            }
+        }
+        _ => Err(parse_error("unsupported CBOR value")),
+    }
+}
+
+/// Encode an Ed25519 COSE_Key in the layout used by WebAuthn authenticators
+fn encode_cose_key(x: &[u8; 32], d: Option<&[u8]>) -> Vec<u8> {
+    let mut cbor = Vec::with_capacity(80);
+    let len = if d.is_some() { 5 } else { 4 };
+
+    cbor.push(MAJOR_MAP << 5 | len);
+    write_int(&mut cbor, LABEL_KTY);
+    write_int(&mut cbor, KTY_OKP);
+    write_int(&mut cbor, LABEL_ALG);
+    write_int(&mut cbor, ALG_EDDSA);
+    write_int(&mut cbor, LABEL_CRV);
+    write_int(&mut cbor, CRV_ED25519);
+    write_int(&mut cbor, LABEL_X);
+    write_bytes(&mut cbor, x);
+
+    if let Some(d) = d {
+        write_int(&mut cbor, LABEL_D);
+        write_bytes(&mut cbor, d);
+    }
+
+    cbor
+}
+
+/// Write a small CBOR integer (i.e. in the range -24..24)
+fn write_int(cbor: &mut Vec<u8>, value: i64) {
+    debug_assert!((-24..24).contains(&value));
+
+    if value >= 0 {
+        cbor.push(MAJOR_UNSIGNED << 5 | value as u8);
+    } else {
+        cbor.push(MAJOR_NEGATIVE << 5 | (-1 - value) as u8);
+    }
+}
+
+/// Write a CBOR byte string shorter than 256 bytes
+fn write_bytes(cbor: &mut Vec<u8>, bytes: &[u8]) {
+    cbor.push(MAJOR_BYTES << 5 | 24);
+    cbor.push(bytes.len() as u8);
+    cbor.extend_from_slice(bytes);
+}
+
+/// Create a `ParseError` with the given description
+fn parse_error(description: &str) -> Error {
+    Error::new(ErrorKind::ParseError, Some(description))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed,
+    };
+    use std::vec::Vec;
+
+    /// Ed25519 credential public key in the layout emitted by WebAuthn
+    /// authenticators in attestation data (WebAuthn Level 2, Section 6.5.1.1),
+    /// i.e. `{1: 1, 3: -8, -1: 6, -2: x}` for the RFC 8032 test 1 key
+    const WEBAUTHN_PUBLIC_KEY: &[u8] = &[
+        0xa4, 0x01, 0x01, 0x03, 0x27, 0x20, 0x06, 0x21, 0x58, 0x20, 0xd7, 0x5a, 0x98, 0x01, 0x82,
+        0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3,
+        0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07, 0x51, 0x1a,
+    ];
+
+    /// Ed25519 private key from RFC 8152 Appendix C.7.2, with a `kid` of "11"
+    /// and parameters in a different order
+    const RFC8152_PRIVATE_KEY: &[u8] = &[
+        0xa5, 0x01, 0x01, 0x02, 0x42, 0x31, 0x31, 0x20, 0x06, 0x23, 0x58, 0x20, 0x9d, 0x61, 0xb1,
+        0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c, 0xc4, 0x44, 0x49,
+        0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae, 0x7f, 0x60, 0x21,
+        0x58, 0x20, 0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9,
+        0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68,
+        0xf7, 0x07, 0x51, 0x1a,
+    ];
+
+    fn test_verifier() -> Ed25519Verifier {
+        Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap()
+    }
+
+    #[test]
+    fn webauthn_public_key() {
+        let verifier = Ed25519Verifier::from_cose_key(WEBAUTHN_PUBLIC_KEY).unwrap();
+        assert_eq!(verifier, test_verifier());
+        assert_eq!(verifier.to_cose_key(), WEBAUTHN_PUBLIC_KEY);
+    }
+
+    #[test]
+    fn rfc8152_private_key() {
+        let signer = Ed25519Signer::from_cose_key(RFC8152_PRIVATE_KEY).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), TEST_VECTORS[0].pk);
+
+        // The private key is also a valid public key
+        let verifier = Ed25519Verifier::from_cose_key(RFC8152_PRIVATE_KEY).unwrap();
+        assert_eq!(verifier, test_verifier());
+    }
+
+    #[test]
+    fn signer_round_trip() {
+        let seed = ed25519::Seed::from_bytes(TEST_VECTORS[1].sk).unwrap();
+        let signer = Ed25519Signer::from(&seed);
+        let cose_key = signer.to_cose_key().unwrap();
+
+        let decoded = Ed25519Signer::from_cose_key(&cose_key).unwrap();
+        assert_eq!(
+            decoded.to_seed().unwrap().as_secret_slice(),
+            TEST_VECTORS[1].sk
+        );
+    }
+
+    #[test]
+    fn mismatched_public_key() {
+        let mut cose_key = RFC8152_PRIVATE_KEY.to_vec();
+        *cose_key.last_mut().unwrap() ^= 1;
+
+        let err = Ed25519Signer::from_cose_key(&cose_key).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn unknown_kty_crv_or_alg() {
+        for &(index, value) in &[
+            // kty: EC2
+            (2, 0x02),
+            // crv: X25519
+            (6, 0x04),
+            // alg: ES256
+            (4, 0x26),
+        ] {
+            let mut cose_key = WEBAUTHN_PUBLIC_KEY.to_vec();
+            cose_key[index] = value;
+
+            let err = Ed25519Verifier::from_cose_key(&cose_key).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn malformed_cose_key() {
+        let mut duplicate_kty = WEBAUTHN_PUBLIC_KEY.to_vec();
+        duplicate_kty[0] += 1;
+        duplicate_kty.extend_from_slice(&[0x01, 0x01]);
+
+        let mut trailing_data = WEBAUTHN_PUBLIC_KEY.to_vec();
+        trailing_data.push(0);
+
+        let inputs: Vec<&[u8]> = vec![
+            &[],
+            &[0x80],
+            &WEBAUTHN_PUBLIC_KEY[..WEBAUTHN_PUBLIC_KEY.len() - 1],
+            &duplicate_kty,
+            &trailing_data,
+        ];
+
+        for cose_key in inputs {
+            let err = Ed25519Verifier::from_cose_key(cose_key).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Minimal DER support for the fixed-size structures used by Ed25519 keys
+
+use alloc::vec::Vec;
+use signatory::error::{Error, ErrorKind};
+
+use crate::Algorithm;
+
+/// Universal DER tags common to all Ed25519 key structures
+pub(crate) const TAG_OID: u8 = 0x06;
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+
+/// Parse an `AlgorithmIdentifier`, returning `ParseError` unless it is
+/// Ed25519 with absent parameters (RFC 8410 Section 3)
+pub(crate) fn read_ed25519_algorithm(input: &mut &[u8]) -> Result<(), Error> {
+    let mut algorithm = read_tlv(input, TAG_SEQUENCE)?;
+
+    if read_tlv(&mut algorithm, TAG_OID)? != Algorithm::Ed25519.oid_der() {
+        return Err(parse_error("algorithm is not Ed25519"));
+    }
+
+    ensure_empty(algorithm)
+}
+
+/// Write the Ed25519 `AlgorithmIdentifier` with absent parameters
+pub(crate) fn write_ed25519_algorithm(der: &mut Vec<u8>) {
+    let oid = Algorithm::Ed25519.oid_der();
+    der.extend_from_slice(&[TAG_SEQUENCE, 2 + oid.len() as u8, TAG_OID, oid.len() as u8]);
+    der.extend_from_slice(oid);
+}
+
+/// Read a DER tag-length-value with the given tag, returning its value and
+/// advancing the input past it
+pub(crate) fn read_tlv<'a>(input: &mut &'a [u8], tag: u8) -> Result<&'a [u8], Error> {
+    if input.len() < 2 || input[0] != tag {
+        return Err(parse_error("unexpected DER tag"));
+    }
+
+    // Only short-form lengths are needed for Ed25519 keys
+    let len = input[1] as usize;
+    if len >= 0x80 || input.len() < 2 + len {
+        return Err(parse_error("invalid DER length"));
+    }
+
+    let value = &input[2..2 + len];
+    *input = &input[2 + len..];
+    Ok(value)
+}
+
+/// Ensure the given DER input has been fully consumed
+pub(crate) fn ensure_empty(input: &[u8]) -> Result<(), Error> {
+    if input.is_empty() {
+        Ok(())
+    } else {
+        Err(parse_error("unexpected trailing DER data"))
+    }
+}
+
+/// Create a `ParseError` with the given description
+pub(crate) fn parse_error(description: &str) -> Error {
+    Error::new(ErrorKind::ParseError, Some(description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_ed25519_algorithm, write_ed25519_algorithm};
+    use crate::Algorithm;
+    use std::vec::Vec;
+
+    #[test]
+    fn algorithm_identifier_round_trip() {
+        let mut der = Vec::new();
+        write_ed25519_algorithm(&mut der);
+        assert_eq!(der, [0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70]);
+        assert!(der.ends_with(Algorithm::Ed25519.oid_der()));
+
+        let mut input = &der[..];
+        read_ed25519_algorithm(&mut input).unwrap();
+        assert!(input.is_empty());
+    }
+}
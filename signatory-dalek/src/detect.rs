@@ -0,0 +1,160 @@
+//! Parsing of public keys in any supported format, detected automatically
+
+use core::str;
+use signatory::error::{Error, ErrorKind};
+
+use crate::Ed25519Verifier;
+
+/// Prefix of PEM documents
+const PEM_PREFIX: &str = "-----BEGIN";
+
+/// Prefix of `did:key` DIDs
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// Length of a hex-encoded public key
+const HEX_PUBLIC_KEY_SIZE: usize = 64;
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from a public key in whichever format it
+    /// is given, e.g. as pasted by an operator. Surrounding whitespace is
+    /// ignored, and the format is detected as follows:
+    ///
+    /// - `-----BEGIN`: PEM-encoded SubjectPublicKeyInfo (requires the `pem`
+    ///   feature)
+    /// - `did:key:`: `did:key` DID (requires the `multibase` feature)
+    /// - 64 hexadecimal characters (of either case): hex
+    /// - anything else: standard base64 (requires the `base64` feature)
+    ///
+    /// Returns `ParseError` if the format is unrecognized, its feature is not
+    /// enabled, or the key is malformed, and `KeyInvalid` if the public key
+    /// is not a valid Edwards point.
+    pub fn from_any(encoded: &str) -> Result<Self, Error> {
+        let encoded = encoded.trim();
+
+        if encoded.starts_with(PEM_PREFIX) {
+            return Self::from_any_pem(encoded);
+        }
+
+        if encoded.starts_with(DID_KEY_PREFIX) {
+            return Self::from_any_did_key(encoded);
+        }
+
+        if encoded.len() == HEX_PUBLIC_KEY_SIZE && encoded.bytes().all(|b| b.is_ascii_hexdigit()) {
+            // `from_hex` only accepts lower-case hex
+            let mut hex = [0u8; HEX_PUBLIC_KEY_SIZE];
+
+            for (lower, byte) in hex.iter_mut().zip(encoded.bytes()) {
+                *lower = byte.to_ascii_lowercase();
+            }
+
+            return Self::from_hex(str::from_utf8(&hex).unwrap());
+        }
+
+        Self::from_any_base64(encoded)
+    }
+
+    #[cfg(feature = "pem")]
+    fn from_any_pem(encoded: &str) -> Result<Self, Error> {
+        Self::from_spki_pem(encoded)
+    }
+
+    #[cfg(not(feature = "pem"))]
+    fn from_any_pem(_encoded: &str) -> Result<Self, Error> {
+        Err(Error::new(
+            ErrorKind::ParseError,
+            Some("PEM public keys require the `pem` feature"),
+        ))
+    }
+
+    #[cfg(feature = "multibase")]
+    fn from_any_did_key(encoded: &str) -> Result<Self, Error> {
+        Self::from_did_key(encoded)
+    }
+
+    #[cfg(not(feature = "multibase"))]
+    fn from_any_did_key(_encoded: &str) -> Result<Self, Error> {
+        Err(Error::new(
+            ErrorKind::ParseError,
+            Some("did:key public keys require the `multibase` feature"),
+        ))
+    }
+
+    #[cfg(feature = "base64")]
+    fn from_any_base64(encoded: &str) -> Result<Self, Error> {
+        Self::decode_base64(encoded.as_bytes()).map_err(|e| {
+            if e.kind() == ErrorKind::ParseError {
+                unrecognized_format()
+            } else {
+                e
+            }
+        })
+    }
+
+    #[cfg(not(feature = "base64"))]
+    fn from_any_base64(_encoded: &str) -> Result<Self, Error> {
+        Err(unrecognized_format())
+    }
+}
+
+/// Error for inputs which are not in any recognized format
+fn unrecognized_format() -> Error {
+    Error::new(
+        ErrorKind::ParseError,
+        Some("unrecognized public key format"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Verifier;
+    use signatory::{ed25519::TEST_VECTORS, error::ErrorKind};
+    use std::string::ToString;
+
+    fn expected() -> Ed25519Verifier {
+        Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap()
+    }
+
+    #[test]
+    fn detects_hex() {
+        let hex = expected().to_string();
+        assert_eq!(Ed25519Verifier::from_any(&hex).unwrap(), expected());
+        assert_eq!(
+            Ed25519Verifier::from_any(&hex.to_uppercase()).unwrap(),
+            expected()
+        );
+        assert_eq!(
+            Ed25519Verifier::from_any(&format!("  {}\n", hex)).unwrap(),
+            expected()
+        );
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn detects_base64() {
+        let verifier =
+            Ed25519Verifier::from_any("11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=").unwrap();
+        assert_eq!(verifier, expected());
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn detects_pem() {
+        let pem = expected().to_spki_pem().unwrap();
+        assert_eq!(Ed25519Verifier::from_any(&pem).unwrap(), expected());
+    }
+
+    #[cfg(feature = "multibase")]
+    #[test]
+    fn detects_did_key() {
+        let did = expected().to_did_key();
+        assert_eq!(Ed25519Verifier::from_any(&did).unwrap(), expected());
+    }
+
+    #[test]
+    fn rejects_unrecognized_format() {
+        for input in &["", "not a public key", "0x1234", "d75a980182b10ab7"] {
+            let err = Ed25519Verifier::from_any(input).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! `did:key` (W3C CCG DID method) support for Ed25519 public keys
+//!
+//! Keys are encoded as `did:key:z<base58btc(0xed 0x01 || public key)>`,
+//! where `z` is the multibase prefix for base58btc and `0xed 0x01` is the
+//! unsigned varint encoding of the `ed25519-pub` multicodec.
+
+use alloc::{string::String, vec::Vec};
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+
+use crate::{decode_public_key, Ed25519Verifier};
+
+/// Prefix of a `did:key` DID using base58btc multibase encoding
+const DID_KEY_PREFIX: &str = "did:key:z";
+
+/// Unsigned varint encoding of the `ed25519-pub` multicodec (0xed)
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+
+/// Size of a multicodec-prefixed Ed25519 public key
+const MULTICODEC_KEY_SIZE: usize = ED25519_MULTICODEC.len() + ed25519::PUBLIC_KEY_SIZE;
+
+/// Bitcoin base58 alphabet
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from a `did:key` DID (i.e. `did:key:z6Mk...`).
+    ///
+    /// Returns `ParseError` if the DID is malformed or its multicodec is not
+    /// `ed25519-pub` (e.g. an X25519 or secp256k1 key), and `KeyInvalid` if
+    /// the public key is not a valid Edwards point.
+    pub fn from_did_key(did: &str) -> Result<Self, Error> {
+        if !did.starts_with(DID_KEY_PREFIX) {
+            return Err(parse_error("expected did:key with base58btc encoding"));
+        }
+
+        let bytes = decode_base58(&did[DID_KEY_PREFIX.len()..])?;
+
+        if bytes[..2] != ED25519_MULTICODEC {
+            return Err(parse_error("multicodec is not ed25519-pub"));
+        }
+
+        decode_public_key(&bytes[2..]).map(Ed25519Verifier)
+    }
+
+    /// Encode this verifier's public key as a `did:key` DID
+    pub fn to_did_key(&self) -> String {
+        let mut bytes = [0u8; MULTICODEC_KEY_SIZE];
+        bytes[..2].copy_from_slice(&ED25519_MULTICODEC);
+        bytes[2..].copy_from_slice(self.0.as_bytes());
+
+        let mut did = String::from(DID_KEY_PREFIX);
+        did.extend(encode_base58(&bytes).into_iter().map(char::from));
+        did
+    }
+}
+
+/// Decode a base58btc string which must represent exactly
+/// `MULTICODEC_KEY_SIZE` bytes, none of them leading zeroes
+fn decode_base58(encoded: &str) -> Result<[u8; MULTICODEC_KEY_SIZE], Error> {
+    // A leading `1` encodes a leading zero byte, which a multicodec never has
+    if encoded.is_empty() || encoded.starts_with('1') {
+        return Err(parse_error("malformed base58btc"));
+    }
+
+    let mut bytes = [0u8; MULTICODEC_KEY_SIZE];
+
+    for c in encoded.bytes() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|&digit| digit == c)
+            .ok_or_else(|| parse_error("malformed base58btc"))? as u32;
+
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        if carry != 0 {
+            return Err(parse_error("base58btc value too long"));
+        }
+    }
+
+    if bytes[0] == 0 {
+        return Err(parse_error("base58btc value too short"));
+    }
+
+    Ok(bytes)
+}
+
+/// Encode the given bytes as base58btc
+fn encode_base58(bytes: &[u8]) -> Vec<u8> {
+    // Little endian base58 digits
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+
+    for &byte in bytes {
+        let mut carry = u32::from(byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeroes = bytes.iter().take_while(|&&byte| byte == 0).count();
+    digits.extend((0..leading_zeroes).map(|_| 0));
+
+    digits
+        .iter()
+        .rev()
+        .map(|&digit| BASE58_ALPHABET[digit as usize])
+        .collect()
+}
+
+/// Create a `ParseError` with the given description
+fn parse_error(description: &str) -> Error {
+    Error::new(ErrorKind::ParseError, Some(description))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use signatory::{ed25519, error::ErrorKind, PublicKeyed};
+
+    /// `did:key` for the all-zero seed from the W3C CCG `did:key` test
+    /// vectors (`ed25519-x25519.json`)
+    const W3C_DID_KEY: &str = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+
+    /// Example Ed25519 `did:key` from the W3C CCG `did:key` specification
+    const SPEC_EXAMPLE_DID_KEY: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+
+    /// `did:key` with the `x25519-pub` multicodec (0xec) and the key bytes of
+    /// `W3C_DID_KEY`
+    const X25519_DID_KEY: &str = "did:key:z6LSfg76x3LLQjPg3AmMPWo7kdWPHeXbnDLDEbYPBESjbxWC";
+
+    #[test]
+    fn w3c_test_vector() {
+        let signer = Ed25519Signer::from(&ed25519::Seed::new([0u8; 32]));
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+
+        assert_eq!(verifier.to_did_key(), W3C_DID_KEY);
+        assert_eq!(
+            Ed25519Verifier::from_did_key(W3C_DID_KEY).unwrap(),
+            verifier
+        );
+    }
+
+    #[test]
+    fn spec_example_round_trip() {
+        let verifier = Ed25519Verifier::from_did_key(SPEC_EXAMPLE_DID_KEY).unwrap();
+        assert_eq!(verifier.to_did_key(), SPEC_EXAMPLE_DID_KEY);
+    }
+
+    #[test]
+    fn rejects_other_multicodecs() {
+        let err = Ed25519Verifier::from_did_key(X25519_DID_KEY).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn rejects_malformed_did_keys() {
+        for did in &[
+            "",
+            "did:key:",
+            "did:key:z",
+            "did:web:example.com",
+            // base64 multibase prefix
+            "did:key:m7QHtAvtERLqc1cikSm8qHWVfxLqeHrFndklJn1hwGCxhUA",
+            // '0' is not in the base58 alphabet
+            "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDoo0p",
+            // Truncated
+            "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooW",
+            // Extended
+            "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWpp",
+            // Leading zero byte
+            "did:key:z16MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp",
+        ] {
+            let err = Ed25519Verifier::from_did_key(did).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError, "{}", did);
+        }
+    }
+}
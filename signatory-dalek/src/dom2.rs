@@ -0,0 +1,72 @@
+//! Ed25519ph signing under context strings which need not be `'static`
+//!
+//! ed25519-dalek only accepts `'static` Ed25519ph contexts, so this builds
+//! the `dom2` prefix of RFC 8032 Section 5.1 itself, allowing contexts which
+//! are only known at runtime.
+
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, scalar::Scalar};
+use ed25519_dalek::{ExpandedSecretKey, Keypair};
+use sha2::{Digest, Sha512};
+use signatory::ed25519;
+
+use crate::{clear_bytes, clear_scalar, context::MAX_CONTEXT_SIZE};
+
+/// Prefix of `dom2`, which is followed by the Ed25519ph flag, the context
+/// length, and the context itself
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// Value of the `dom2` flag for Ed25519ph
+const PH_FLAG: u8 = 1;
+
+/// Sign the 64-byte prehash of a message with Ed25519ph under the given
+/// context, which must be at most `MAX_CONTEXT_SIZE` bytes.
+///
+/// With `a` the secret scalar, `prefix` the nonce prefix, `A` the public key,
+/// `PH(M)` the prehash, and `dom2(C)` the byte string
+/// `"SigEd25519 no Ed25519 collisions" || 1 || len(C) || C`, the signature is
+/// `R || s` where:
+///
+/// - `r = SHA-512(dom2(C) || prefix || PH(M)) mod l`
+/// - `R = [r]B`
+/// - `k = SHA-512(dom2(C) || R || A || PH(M)) mod l`
+/// - `s = (r + k * a) mod l`
+pub(crate) fn sign_prehashed(
+    keypair: &Keypair,
+    prehash: &[u8],
+    context: &[u8],
+) -> ed25519::Signature {
+    debug_assert!(context.len() <= MAX_CONTEXT_SIZE);
+    let dom2 = || {
+        Sha512::new()
+            .chain(DOM2_PREFIX)
+            .chain([PH_FLAG, context.len() as u8])
+            .chain(context)
+    };
+
+    let mut expanded = ExpandedSecretKey::from(&keypair.secret).to_bytes();
+    let mut scalar_bytes = [0u8; 32];
+    scalar_bytes.copy_from_slice(&expanded[..32]);
+    let mut secret_scalar = Scalar::from_bits(scalar_bytes);
+
+    let mut r = Scalar::from_hash(dom2().chain(&expanded[32..]).chain(prehash));
+    let big_r = (&r * &ED25519_BASEPOINT_TABLE).compress();
+
+    let k = Scalar::from_hash(
+        dom2()
+            .chain(big_r.as_bytes())
+            .chain(keypair.public.as_bytes())
+            .chain(prehash),
+    );
+    let s = r + k * secret_scalar;
+
+    // As in `sign_hedged`, clearing these is best effort
+    clear_scalar(&mut secret_scalar);
+    clear_scalar(&mut r);
+    clear_bytes(&mut expanded);
+    clear_bytes(&mut scalar_bytes);
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(big_r.as_bytes());
+    signature[32..].copy_from_slice(s.as_bytes());
+    ed25519::Signature::new(signature)
+}
@@ -0,0 +1,111 @@
+//! Allocation-free verification against a fixed-capacity set of trusted
+//! Ed25519 public keys
+
+use heapless::Vec;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Verifier,
+};
+
+use crate::Ed25519Verifier;
+
+/// Ed25519 verifier which accepts signatures made by any one of up to `N`
+/// trusted public keys, stored inline without heap allocation.
+///
+/// This is the `no_std`-friendly counterpart of `MultiVerifier`, e.g. for
+/// firmware holding a small allowlist of trusted keys.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FixedMultiVerifier<const N: usize> {
+    verifiers: Vec<Ed25519Verifier, N>,
+}
+
+impl<const N: usize> FixedMultiVerifier<N> {
+    /// Create a new, empty fixed-capacity multi-key verifier
+    pub fn new() -> Self {
+        FixedMultiVerifier {
+            verifiers: Vec::new(),
+        }
+    }
+
+    /// Trust the given verifier, returning `ProviderError` if `N` verifiers
+    /// are already trusted
+    pub fn push(&mut self, verifier: Ed25519Verifier) -> Result<(), Error> {
+        self.verifiers.push(verifier).map_err(|_| {
+            Error::new(
+                ErrorKind::ProviderError,
+                Some("fixed-capacity verifier set is full"),
+            )
+        })
+    }
+
+    /// Borrow the verifiers trusted by this multi-key verifier
+    pub fn verifiers(&self) -> &[Ed25519Verifier] {
+        &self.verifiers
+    }
+
+    /// Verify the given signature against each trusted key in order,
+    /// returning the index of the first one it is valid for, or
+    /// `SignatureInvalid` if it is valid for none of them
+    pub fn verify_any(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<usize, Error> {
+        self.verifiers
+            .iter()
+            .position(|verifier| verifier.verify(msg, sig).is_ok())
+            .ok_or_else(|| ErrorKind::SignatureInvalid.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedMultiVerifier;
+    use crate::Ed25519Verifier;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        Signature,
+    };
+
+    fn fixed_multi_verifier() -> FixedMultiVerifier<3> {
+        let mut verifiers = FixedMultiVerifier::new();
+
+        for vector in &TEST_VECTORS[..3] {
+            verifiers
+                .push(Ed25519Verifier::from_bytes(vector.pk).unwrap())
+                .unwrap();
+        }
+
+        verifiers
+    }
+
+    #[test]
+    fn matches_last_key() {
+        let vector = &TEST_VECTORS[2];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+        assert_eq!(
+            fixed_multi_verifier().verify_any(vector.msg, &sig).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn matches_no_key() {
+        let vector = &TEST_VECTORS[3];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+        let err = fixed_multi_verifier()
+            .verify_any(vector.msg, &sig)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_push_when_full() {
+        let mut verifiers = fixed_multi_verifier();
+        assert_eq!(verifiers.verifiers().len(), 3);
+
+        let err = verifiers
+            .push(Ed25519Verifier::from_bytes(TEST_VECTORS[3].pk).unwrap())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+        assert_eq!(verifiers.verifiers().len(), 3);
+    }
+}
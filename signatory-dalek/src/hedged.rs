@@ -0,0 +1,118 @@
+//! Hedged Ed25519 signing, mixing fresh randomness into the nonce
+
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, scalar::Scalar};
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+use signatory::{ed25519, error::Error};
+
+use crate::{clear_bytes, clear_scalar, Ed25519Signer};
+
+/// Number of random bytes mixed into the nonce of each hedged signature
+const HEDGE_SIZE: usize = 32;
+
+impl Ed25519Signer {
+    /// Sign a message with a "hedged" nonce which mixes fresh randomness from
+    /// the given RNG into RFC 8032's deterministic nonce derivation.
+    ///
+    /// Deterministic nonces mean a fault injected while signing the same
+    /// message twice can leak the secret key; hedging makes every signature
+    /// use a different nonce, while a broken RNG degrades to (at worst) the
+    /// security of deterministic signing rather than leaking the key.
+    ///
+    /// With `a` the secret scalar, `prefix` the nonce prefix (the upper half
+    /// of the expanded secret key), `A` the public key, `M` the message, and
+    /// `Z` 32 bytes read from `rng`, the signature is `R || s` where:
+    ///
+    /// - `r = SHA-512(prefix || Z || M) mod l`
+    /// - `R = [r]B`
+    /// - `k = SHA-512(R || A || M) mod l`
+    /// - `s = (r + k * a) mod l`
+    ///
+    /// i.e. RFC 8032 Ed25519 signing, with `Z` inserted between the prefix
+    /// and the message when deriving `r`. The resulting signatures are not
+    /// deterministic, but verify as ordinary Ed25519 signatures.
+    ///
+    /// Returns `ProviderError` if the secret key has been scrubbed by
+    /// `zeroize_secret`.
+    pub fn sign_hedged<R>(&self, msg: &[u8], rng: &mut R) -> Result<ed25519::Signature, Error>
+    where
+        R: CryptoRng + RngCore,
+    {
+        self.ensure_enabled()?;
+
+        let mut hedge = [0u8; HEDGE_SIZE];
+        rng.fill_bytes(&mut hedge);
+
+        let mut expanded = self.expanded.to_bytes();
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&expanded[..32]);
+        let mut secret_scalar = Scalar::from_bits(scalar_bytes);
+
+        let mut r = Scalar::from_hash(
+            Sha512::new()
+                .chain(&expanded[32..])
+                .chain(&hedge[..])
+                .chain(msg),
+        );
+        let big_r = (&r * &ED25519_BASEPOINT_TABLE).compress();
+
+        let k = Scalar::from_hash(
+            Sha512::new()
+                .chain(big_r.as_bytes())
+                .chain(self.public.as_bytes())
+                .chain(msg),
+        );
+        let s = r + k * secret_scalar;
+
+        // Clear the secret scalar, the nonce `r` (from which the secret scalar
+        // can be recovered given the signature), and our copies of the
+        // expanded secret key. This is best effort: curve25519-dalek 1.x
+        // scalars cannot be zeroized with volatile writes, so the compiler may
+        // elide their overwrites or leave copies in registers or temporaries.
+        clear_scalar(&mut secret_scalar);
+        clear_scalar(&mut r);
+        clear_bytes(&mut expanded);
+        clear_bytes(&mut scalar_bytes);
+        clear_bytes(&mut hedge);
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(big_r.as_bytes());
+        signature[32..].copy_from_slice(s.as_bytes());
+        Ok(ed25519::Signature::new(signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519StrictVerifier};
+    use core::convert::TryFrom;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        Signer, Verifier,
+    };
+
+    #[test]
+    fn hedged_signatures_differ_and_verify() {
+        let mut csprng = rand_os::OsRng::new().unwrap();
+
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let verifier = signer.verifier();
+            let strict_verifier = Ed25519StrictVerifier::from_public_key(
+                &ed25519::PublicKey::from_bytes(vector.pk).unwrap(),
+            )
+            .unwrap();
+
+            let sig1 = signer.sign_hedged(vector.msg, &mut csprng).unwrap();
+            let sig2 = signer.sign_hedged(vector.msg, &mut csprng).unwrap();
+            let deterministic = signer.sign(vector.msg).unwrap();
+            assert_ne!(sig1, sig2);
+            assert_ne!(sig1, deterministic);
+
+            for sig in &[sig1, sig2] {
+                assert!(verifier.verify(vector.msg, sig).is_ok());
+                assert!(strict_verifier.verify(vector.msg, sig).is_ok());
+            }
+        }
+    }
+}
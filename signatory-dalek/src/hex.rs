@@ -0,0 +1,73 @@
+//! Hexadecimal encoding of Ed25519 signatures
+
+use alloc::string::String;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+use subtle_encoding::{Encoding, Hex};
+
+/// Encode a signature as 128 lower-case hexadecimal characters
+pub fn signature_to_hex(sig: &ed25519::Signature) -> String {
+    Hex::default().encode_to_string(sig.as_bytes()).unwrap()
+}
+
+/// Decode a signature from 128 lower-case hexadecimal characters, returning
+/// `ParseError` if the input is not valid hex or not exactly 64 bytes long
+pub fn signature_from_hex(hex: &str) -> Result<ed25519::Signature, Error> {
+    let mut bytes = [0u8; ed25519::SIGNATURE_SIZE];
+
+    if hex.len() != bytes.len() * 2
+        || Hex::default()
+            .decode_to_slice(hex.as_bytes(), &mut bytes)
+            .is_err()
+    {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("expected 128 lower-case hex characters"),
+        ));
+    }
+
+    Ok(ed25519::Signature::new(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{signature_from_hex, signature_to_hex};
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        Signature,
+    };
+
+    #[test]
+    fn round_trip() {
+        for vector in TEST_VECTORS {
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+            let hex = signature_to_hex(&sig);
+
+            assert_eq!(hex.len(), 128);
+            assert_eq!(hex, hex.to_lowercase());
+            assert_eq!(signature_from_hex(&hex).unwrap(), sig);
+        }
+    }
+
+    #[test]
+    fn rejects_63_bytes() {
+        let sig = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let hex = signature_to_hex(&sig);
+        let err = signature_from_hex(&hex[..126]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        let sig = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let hex = signature_to_hex(&sig);
+
+        for hex in &[format!("g{}", &hex[1..]), hex.to_uppercase()] {
+            let err = signature_from_hex(hex).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
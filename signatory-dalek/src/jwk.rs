@@ -0,0 +1,200 @@
+//! JSON Web Key (RFC 7517) support for Ed25519 keys as described in RFC 8037
+
+use serde_json::{Map, Value};
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    PublicKeyed,
+};
+use std::string::String;
+use subtle_encoding::{Base64, Encoding};
+
+use crate::{decode_public_key, Ed25519Signer, Ed25519Verifier};
+
+/// Length of a 32-byte value encoded as unpadded base64url
+const ENCODED_KEY_LEN: usize = 43;
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from the `x` member of a JSON Web Key.
+    ///
+    /// Returns `ParseError` if the JWK is malformed or its `kty`/`crv` are not
+    /// `OKP`/`Ed25519`, and `KeyInvalid` if the public key is not a valid
+    /// Edwards point.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let jwk = parse_ed25519_jwk(jwk)?;
+        let x = decode_member(&jwk, "x")?;
+        decode_public_key(&x).map(Ed25519Verifier)
+    }
+
+    /// Serialize this verifier's public key as a JSON Web Key
+    pub fn to_jwk(&self) -> String {
+        format!(
+            r#"{{"kty":"OKP","crv":"Ed25519","x":"{}"}}"#,
+            encode_base64url(self.0.as_bytes())
+        )
+    }
+}
+
+impl Ed25519Signer {
+    /// Create a new Ed25519 signer from the `d` member of a JSON Web Key.
+    ///
+    /// Returns `ParseError` if the JWK is malformed or its `kty`/`crv` are not
+    /// `OKP`/`Ed25519`, and `KeyInvalid` if it contains an `x` member which
+    /// does not correspond to `d`.
+    pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+        let jwk = parse_ed25519_jwk(jwk)?;
+        let signer = Self::from_seed(&ed25519::Seed::new(decode_member(&jwk, "d")?))?;
+
+        if jwk.contains_key("x") && decode_member(&jwk, "x")? != *signer.public_key()?.as_bytes() {
+            return Err(Error::new(
+                ErrorKind::KeyInvalid,
+                Some("public key does not match seed"),
+            ));
+        }
+
+        Ok(signer)
+    }
+}
+
+/// Parse a JWK, ensuring it is an Ed25519 octet key pair
+fn parse_ed25519_jwk(jwk: &str) -> Result<Map<String, Value>, Error> {
+    let jwk = match serde_json::from_str(jwk) {
+        Ok(Value::Object(jwk)) => jwk,
+        _ => return Err(parse_error("malformed JWK")),
+    };
+
+    if jwk.get("kty").and_then(Value::as_str) != Some("OKP") {
+        return Err(parse_error("JWK kty is not OKP"));
+    }
+
+    if jwk.get("crv").and_then(Value::as_str) != Some("Ed25519") {
+        return Err(parse_error("JWK crv is not Ed25519"));
+    }
+
+    Ok(jwk)
+}
+
+/// Decode a 32-byte base64url JWK member
+fn decode_member(jwk: &Map<String, Value>, name: &str) -> Result<[u8; 32], Error> {
+    let encoded = jwk
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or_else(|| parse_error("missing JWK member"))?;
+
+    if encoded.len() != ENCODED_KEY_LEN {
+        return Err(parse_error("expected 32-byte JWK member"));
+    }
+
+    // Translate unpadded base64url into padded standard Base64
+    let mut base64 = [b'='; ENCODED_KEY_LEN + 1];
+    for (dst, src) in base64.iter_mut().zip(encoded.bytes()) {
+        *dst = match src {
+            b'-' => b'+',
+            b'_' => b'/',
+            b'+' | b'/' => return Err(parse_error("malformed base64url")),
+            other => other,
+        };
+    }
+
+    let mut bytes = [0u8; 32];
+    match Base64::default().decode_to_slice(&base64, &mut bytes) {
+        Ok(32) => Ok(bytes),
+        _ => Err(parse_error("malformed base64url")),
+    }
+}
+
+/// Encode the given 32 bytes as unpadded base64url
+fn encode_base64url(bytes: &[u8; 32]) -> String {
+    let mut base64 = [0u8; ENCODED_KEY_LEN + 1];
+    Base64::default()
+        .encode_to_slice(bytes, &mut base64)
+        .unwrap();
+
+    base64[..ENCODED_KEY_LEN]
+        .iter()
+        .map(|&byte| match byte {
+            b'+' => '-',
+            b'/' => '_',
+            other => char::from(other),
+        })
+        .collect()
+}
+
+/// Create a `ParseError` with the given description
+fn parse_error(description: &str) -> Error {
+    Error::new(ErrorKind::ParseError, Some(description))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use signatory::{
+        ed25519::{PublicKey, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed, Signer,
+    };
+
+    /// Ed25519 private key from RFC 8037 Appendix A.1, as used by the test
+    /// suites of JOSE libraries such as `jose` and `jose4j`
+    const RFC8037_PRIVATE_KEY: &str = r#"{"kty":"OKP","crv":"Ed25519",
+        "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+        "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+
+    /// Ed25519 public key from RFC 8037 Appendix A.2
+    const RFC8037_PUBLIC_KEY: &str =
+        r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+
+    #[test]
+    fn rfc8037_keys() {
+        let verifier = Ed25519Verifier::from_jwk(RFC8037_PUBLIC_KEY).unwrap();
+        assert_eq!(
+            verifier,
+            Ed25519Verifier::from(&PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap())
+        );
+        assert_eq!(verifier.to_jwk(), RFC8037_PUBLIC_KEY);
+
+        let signer = Ed25519Signer::from_jwk(RFC8037_PRIVATE_KEY).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), TEST_VECTORS[0].pk);
+        assert_eq!(
+            signer.sign(TEST_VECTORS[0].msg).unwrap().as_ref(),
+            TEST_VECTORS[0].sig
+        );
+    }
+
+    #[test]
+    fn mismatched_public_key() {
+        let jwk = RFC8037_PRIVATE_KEY.replace("11qYAYKx", "11qYAYKy");
+        let err = Ed25519Signer::from_jwk(&jwk).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn wrong_kty_or_crv() {
+        for jwk in &[
+            RFC8037_PUBLIC_KEY.replace("OKP", "EC"),
+            RFC8037_PUBLIC_KEY.replace("Ed25519", "X25519"),
+            RFC8037_PUBLIC_KEY.replace(r#""crv":"Ed25519","#, ""),
+        ] {
+            let err = Ed25519Verifier::from_jwk(jwk).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        let jwk = RFC8037_PRIVATE_KEY.replace("Ed25519", "Ed448");
+        let err = Ed25519Signer::from_jwk(&jwk).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn malformed_jwk() {
+        for jwk in &[
+            "",
+            "[]",
+            r#"{"kty":"OKP","crv":"Ed25519"}"#,
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHUR"}"#,
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        ] {
+            let err = Ed25519Verifier::from_jwk(jwk).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError, "{}", jwk);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Verification against a sorted table of public keys indexed by position
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Verifier,
+};
+
+use crate::Ed25519Verifier;
+
+/// Table of peers' public keys, sorted by their byte encoding so every peer
+/// built from the same set of keys agrees on each key's index.
+///
+/// This allows messages to identify their signer with a 2-byte index into the
+/// table rather than a full 32-byte public key. Only the first 65536 keys in
+/// the table are addressable.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KeyTable {
+    verifiers: Vec<Ed25519Verifier>,
+}
+
+impl KeyTable {
+    /// Create a new key table from the given verifiers, sorting them and
+    /// removing any duplicates
+    pub fn new(mut verifiers: Vec<Ed25519Verifier>) -> Self {
+        verifiers.sort();
+        verifiers.dedup();
+        KeyTable { verifiers }
+    }
+
+    /// Borrow the verifiers in this table, in index order
+    pub fn verifiers(&self) -> &[Ed25519Verifier] {
+        &self.verifiers
+    }
+
+    /// Look up the index of the given verifier in this table, if present and
+    /// addressable
+    pub fn index_of(&self, verifier: &Ed25519Verifier) -> Option<u16> {
+        self.verifiers
+            .binary_search(verifier)
+            .ok()
+            .and_then(|index| u16::try_from(index).ok())
+    }
+
+    /// Verify a signature against the key at the given index, returning
+    /// `ParseError` if the index is out of range, and `SignatureInvalid` if
+    /// the signature does not verify
+    pub fn verify_by_index(
+        &self,
+        index: u16,
+        msg: &[u8],
+        sig: &ed25519::Signature,
+    ) -> Result<(), Error> {
+        let verifier = self.verifiers.get(usize::from(index)).ok_or_else(|| {
+            Error::new(ErrorKind::ParseError, Some("key table index out of range"))
+        })?;
+
+        verifier.verify(msg, sig)
+    }
+}
+
+impl From<Vec<Ed25519Verifier>> for KeyTable {
+    fn from(verifiers: Vec<Ed25519Verifier>) -> Self {
+        Self::new(verifiers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyTable;
+    use crate::Ed25519Verifier;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        Signature,
+    };
+    use std::vec::Vec;
+
+    fn key_table() -> KeyTable {
+        TEST_VECTORS
+            .iter()
+            .map(|vector| Ed25519Verifier::from_bytes(vector.pk).unwrap())
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn verifies_by_index() {
+        let table = key_table();
+
+        for vector in TEST_VECTORS {
+            let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+            let index = table.index_of(&verifier).unwrap();
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+
+            assert_eq!(table.verifiers()[usize::from(index)], verifier);
+            assert!(table.verify_by_index(index, vector.msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_index() {
+        let table = key_table();
+        let vector = &TEST_VECTORS[0];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let index = table.index_of(&verifier).unwrap();
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+
+        let other = (index + 1) % table.verifiers().len() as u16;
+        let err = table.verify_by_index(other, vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_out_of_range_index() {
+        let table = key_table();
+        let vector = &TEST_VECTORS[0];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+
+        let index = table.verifiers().len() as u16;
+        let err = table.verify_by_index(index, vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+}
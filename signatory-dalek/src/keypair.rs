@@ -0,0 +1,64 @@
+//! Ed25519 keypairs combining a signer with its matching verifier
+
+use signatory::{ed25519, error::Error};
+
+use crate::{Ed25519Signer, Ed25519Verifier};
+
+/// Ed25519 keypair for ed25519-dalek, providing both an `Ed25519Signer` and
+/// an `Ed25519Verifier` for its public key.
+///
+/// The public key is derived once from the seed, ensuring the signer and
+/// verifier always correspond to each other.
+#[derive(Clone, Debug)]
+pub struct Ed25519KeyPair(Ed25519Signer);
+
+impl Ed25519KeyPair {
+    /// Create a new keypair from a seed, returning `KeyInvalid` if the seed
+    /// is malformed
+    pub fn from_seed(seed: &ed25519::Seed) -> Result<Self, Error> {
+        Ed25519Signer::from_seed(seed).map(Ed25519KeyPair)
+    }
+
+    /// Borrow the signer for this keypair
+    pub fn signer(&self) -> &Ed25519Signer {
+        &self.0
+    }
+
+    /// Obtain a verifier for this keypair's public key
+    pub fn verifier(&self) -> Ed25519Verifier {
+        Ed25519Verifier(self.0.public)
+    }
+
+    /// Get the public key for this keypair
+    pub fn public_key(&self) -> ed25519::PublicKey {
+        ed25519::PublicKey::new(*self.0.public.as_bytes())
+    }
+}
+
+impl<'a> From<&'a ed25519::Seed> for Ed25519KeyPair {
+    fn from(seed: &'a ed25519::Seed) -> Self {
+        Ed25519KeyPair(Ed25519Signer::from(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ed25519KeyPair;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        PublicKeyed, Signer, Verifier,
+    };
+
+    #[test]
+    fn keypair_round_trip() {
+        for vector in TEST_VECTORS {
+            let seed = ed25519::Seed::from_bytes(vector.sk).unwrap();
+            let keypair = Ed25519KeyPair::from_seed(&seed).unwrap();
+            assert_eq!(keypair.public_key().as_ref(), vector.pk);
+            assert_eq!(keypair.signer().public_key().unwrap(), keypair.public_key());
+
+            let signature = keypair.signer().sign(vector.msg).unwrap();
+            assert!(keypair.verifier().verify(vector.msg, &signature).is_ok());
+        }
+    }
+}
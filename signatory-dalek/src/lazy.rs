@@ -0,0 +1,192 @@
+//! Verification against a trusted key which is fetched at verification time
+
+use core::fmt::{self, Debug};
+use signatory::{ed25519, error::Error, Verifier};
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use crate::Ed25519Verifier;
+
+/// Ed25519 verifier which fetches the currently trusted verifier by calling
+/// a user-supplied function on every `verify` call, e.g. to follow key
+/// rotations without rebuilding whatever holds the verifier.
+///
+/// Verifiers created with `new` call the function once per signature.
+/// Verifiers created with `memoized` (with the `std` feature) call it once
+/// and reuse the result until `refresh` is called, for when fetching the key
+/// is expensive and the application knows when it rotates.
+pub struct LazyVerifier<F>
+where
+    F: Fn() -> Result<Ed25519Verifier, Error> + Send + Sync,
+{
+    /// Function which fetches the currently trusted verifier
+    fetch: F,
+
+    /// Most recently fetched verifier, if memoization is enabled
+    #[cfg(feature = "std")]
+    cache: Option<Mutex<Option<Ed25519Verifier>>>,
+}
+
+impl<F> LazyVerifier<F>
+where
+    F: Fn() -> Result<Ed25519Verifier, Error> + Send + Sync,
+{
+    /// Create a new lazy verifier which fetches the trusted verifier by
+    /// calling `fetch`
+    pub fn new(fetch: F) -> Self {
+        LazyVerifier {
+            fetch,
+            #[cfg(feature = "std")]
+            cache: None,
+        }
+    }
+
+    /// Create a new lazy verifier which fetches the trusted verifier by
+    /// calling `fetch` the first time it is needed, and reuses it until
+    /// `refresh` is called. Errors returned by `fetch` are not memoized.
+    #[cfg(feature = "std")]
+    pub fn memoized(fetch: F) -> Self {
+        LazyVerifier {
+            fetch,
+            cache: Some(Mutex::new(None)),
+        }
+    }
+
+    /// Discard the memoized verifier (if any), so the next call to `verify`
+    /// or `current` fetches it again, e.g. after a key rotation
+    #[cfg(feature = "std")]
+    pub fn refresh(&self) {
+        if let Some(cache) = &self.cache {
+            *cache.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+    }
+
+    /// Fetch the currently trusted verifier, or return the memoized one
+    pub fn current(&self) -> Result<Ed25519Verifier, Error> {
+        #[cfg(feature = "std")]
+        {
+            if let Some(cache) = &self.cache {
+                if let Some(verifier) = &*cache.lock().unwrap_or_else(|e| e.into_inner()) {
+                    return Ok(verifier.clone());
+                }
+
+                // Don't hold the lock while fetching, in case `fetch` panics
+                let verifier = (self.fetch)()?;
+                *cache.lock().unwrap_or_else(|e| e.into_inner()) = Some(verifier.clone());
+                return Ok(verifier);
+            }
+        }
+
+        (self.fetch)()
+    }
+}
+
+impl<F> Debug for LazyVerifier<F>
+where
+    F: Fn() -> Result<Ed25519Verifier, Error> + Send + Sync,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LazyVerifier {{ .. }}")
+    }
+}
+
+impl<F> Verifier<ed25519::Signature> for LazyVerifier<F>
+where
+    F: Fn() -> Result<Ed25519Verifier, Error> + Send + Sync,
+{
+    /// Verify a signature against the verifier returned by the fetch
+    /// function, passing through any error it returns
+    fn verify(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        self.current()?.verify(msg, sig)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LazyVerifier;
+    use crate::Ed25519Verifier;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::{Error, ErrorKind},
+        Signature, Verifier,
+    };
+
+    #[test]
+    fn tracks_key_changes() {
+        let current = AtomicUsize::new(0);
+        let verifier = LazyVerifier::new(|| {
+            Ed25519Verifier::from_bytes(TEST_VECTORS[current.load(Ordering::SeqCst)].pk)
+        });
+
+        let sig_0 = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let sig_1 = ed25519::Signature::from_bytes(TEST_VECTORS[1].sig).unwrap();
+
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig_0).is_ok());
+        assert!(verifier.verify(TEST_VECTORS[1].msg, &sig_1).is_err());
+
+        // Rotate to the second key
+        current.store(1, Ordering::SeqCst);
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig_0).is_err());
+        assert!(verifier.verify(TEST_VECTORS[1].msg, &sig_1).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn memoizes_until_refreshed() {
+        let current = AtomicUsize::new(0);
+        let fetches = AtomicUsize::new(0);
+        let verifier = LazyVerifier::memoized(|| {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            Ed25519Verifier::from_bytes(TEST_VECTORS[current.load(Ordering::SeqCst)].pk)
+        });
+
+        let sig_0 = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let sig_1 = ed25519::Signature::from_bytes(TEST_VECTORS[1].sig).unwrap();
+
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig_0).is_ok());
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig_0).is_ok());
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // The rotation is only picked up after a refresh
+        current.store(1, Ordering::SeqCst);
+        assert!(verifier.verify(TEST_VECTORS[1].msg, &sig_1).is_err());
+
+        verifier.refresh();
+        assert!(verifier.verify(TEST_VECTORS[1].msg, &sig_1).is_ok());
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig_0).is_err());
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn does_not_memoize_fetch_errors() {
+        let fetches = AtomicUsize::new(0);
+        let verifier = LazyVerifier::memoized(|| {
+            if fetches.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(ErrorKind::ProviderError.into())
+            } else {
+                Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk)
+            }
+        });
+
+        let sig = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let err = verifier.verify(TEST_VECTORS[0].msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+        assert!(verifier.verify(TEST_VECTORS[0].msg, &sig).is_ok());
+    }
+
+    #[test]
+    fn propagates_fetch_errors() {
+        let verifier = LazyVerifier::new(|| {
+            Err(Error::new(
+                ErrorKind::ProviderError,
+                Some("key store unavailable"),
+            ))
+        });
+
+        let sig = ed25519::Signature::from_bytes(TEST_VECTORS[0].sig).unwrap();
+        let err = verifier.verify(TEST_VECTORS[0].msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+    }
+}
@@ -13,128 +13,2674 @@
     html_root_url = "https://docs.rs/signatory-dalek/0.11.0"
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+#[macro_use]
+extern crate std;
+
 #[cfg(test)]
 #[macro_use]
 extern crate signatory;
+#[cfg(feature = "log")]
+#[macro_use]
+extern crate log;
+#[cfg(feature = "rayon")]
+extern crate rayon_crate as rayon;
+
+#[cfg(feature = "compat")]
+pub extern crate ed25519_compat;
+#[cfg(feature = "signature-traits")]
+pub extern crate signature;
 
+mod aggregate;
+mod algorithm;
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "batch")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod blob;
+mod builder;
+#[cfg(feature = "compat")]
+mod compat;
+mod context;
+#[cfg(feature = "cose")]
+mod cose;
+#[cfg(any(feature = "pkcs8", feature = "spki"))]
+mod der;
+mod detect;
+#[cfg(feature = "multibase")]
+mod did;
+mod dom2;
+#[cfg(feature = "heapless")]
+mod fixed_multi;
+#[cfg(feature = "rand")]
+mod hedged;
+#[cfg(feature = "alloc")]
+mod hex;
+#[cfg(feature = "jwk")]
+mod jwk;
+#[cfg(feature = "alloc")]
+mod key_table;
+mod keypair;
+mod lazy;
+#[cfg(feature = "log")]
+mod logging;
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+#[cfg(feature = "alloc")]
+mod multi;
+#[cfg(feature = "alloc")]
+mod once;
+#[cfg(feature = "openssh")]
+mod openssh;
+#[cfg(feature = "passphrase")]
+mod passphrase;
+#[cfg(feature = "pem")]
+mod pem;
+#[cfg(feature = "pkcs8")]
+mod pkcs8;
+mod precomputed;
+mod prehash;
+#[cfg(feature = "std")]
+mod reader;
+mod receipt;
+#[cfg(feature = "remote")]
+mod remote;
+#[cfg(feature = "alloc")]
+mod report;
+#[cfg(feature = "serde")]
+mod serializers;
+#[cfg(feature = "signature-traits")]
+mod signature_traits;
+#[cfg(feature = "tracing")]
+mod spans;
+#[cfg(feature = "spki")]
+mod spki;
+mod state;
+mod strict;
+mod timestamp;
+#[cfg(feature = "std")]
+mod writer;
+
+pub use crate::aggregate::combine_public_keys;
+pub use crate::algorithm::{Algorithm, ED25519_OID};
+#[cfg(feature = "base64")]
+pub use crate::base64::PUBLIC_KEY_BASE64_SIZE;
+#[cfg(feature = "rayon")]
+pub use crate::batch::verify_batch_par;
+#[cfg(feature = "batch")]
+pub use crate::batch::{verify_batch, verify_batch_prehashed};
+#[cfg(feature = "alloc")]
+pub use crate::blob::{verify_blob, BLOB_HEADER_SIZE};
+pub use crate::builder::{BuiltSigner, Ed25519SignerBuilder};
+#[cfg(feature = "compat")]
+pub use crate::compat::{from_compat_signature, to_compat_signature};
+pub use crate::context::{Context, MAX_CONTEXT_SIZE};
+#[cfg(feature = "heapless")]
+pub use crate::fixed_multi::FixedMultiVerifier;
+#[cfg(feature = "alloc")]
+pub use crate::hex::{signature_from_hex, signature_to_hex};
+#[cfg(feature = "alloc")]
+pub use crate::key_table::KeyTable;
+pub use crate::keypair::Ed25519KeyPair;
+pub use crate::lazy::LazyVerifier;
+#[cfg(feature = "mnemonic")]
+pub use crate::mnemonic::{seed_to_mnemonic, MNEMONIC_WORDS};
+#[cfg(feature = "alloc")]
+pub use crate::multi::MultiVerifier;
+#[cfg(feature = "alloc")]
+pub use crate::once::OnceSigner;
+#[cfg(feature = "passphrase")]
+pub use crate::passphrase::{MIN_SALT_SIZE, PBKDF2_ITERATIONS};
+pub use crate::precomputed::PrecomputedVerifier;
+pub use crate::receipt::SigningReceipt;
+#[cfg(feature = "remote")]
+pub use crate::remote::RemoteEd25519Signer;
+#[cfg(feature = "alloc")]
+pub use crate::report::verify_batch_report;
+pub use crate::state::PrehashState;
+#[cfg(feature = "subtle")]
+pub use crate::strict::signatures_ct_eq;
+pub use crate::strict::{is_canonical_signature, signature_components, Ed25519StrictVerifier};
+pub use crate::timestamp::{TimestampedVerifier, TIMESTAMP_SIZE};
+#[cfg(feature = "std")]
+pub use crate::writer::Ed25519PhWriter;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+use curve25519_dalek::{
+    edwards::CompressedEdwardsY, ristretto::CompressedRistretto, scalar::Scalar,
+};
 use digest::Digest;
-use ed25519_dalek::{Keypair, SecretKey};
+use ed25519_dalek::{ExpandedSecretKey, Keypair, SecretKey};
+#[cfg(feature = "rand")]
+use rand::{CryptoRng, RngCore};
+use sha2::{Sha256, Sha512};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
 
 use signatory::{
     ed25519,
     error::{Error, ErrorKind},
-    PublicKeyed, Signature, Signer, Verifier,
+    PublicKeyed, Signer, Verifier,
 };
 use signatory::{generic_array::typenum::U64, DigestSigner, DigestVerifier};
+use subtle_encoding::{Encoding, Hex};
+
+use crate::prehash::Prehash;
+
+/// Digest used to prehash messages by the Ed25519ph convenience methods, i.e.
+/// SHA-512 as specified in RFC 8032. The `*_with` variants of these methods
+/// accept any other 64-byte output `Digest`, such as a hardware-accelerated
+/// SHA-512 implementation.
+pub type DefaultPhDigest = Sha512;
+
+/// Size of an Ed25519 seed (i.e. unexpanded secret key) in bytes
+pub const SEED_LENGTH: usize = 32;
+
+/// Size of a compressed Edwards-y Ed25519 public key in bytes
+pub const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Size of an Ed25519 signature in bytes
+pub const SIGNATURE_LENGTH: usize = 64;
+
+/// Minimum number of bits which must be set in a seed passed to
+/// `Ed25519Signer::from_seed_checked` (out of 256)
+pub const MIN_SEED_HAMMING_WEIGHT: u32 = 64;
 
 /// Ed25519 signature provider for ed25519-dalek
-pub struct Ed25519Signer(Keypair);
+pub struct Ed25519Signer {
+    /// Seed this signer was constructed from, or `None` if it was constructed
+    /// from an expanded secret key (from which the seed is unrecoverable)
+    secret: Option<SecretKey>,
+
+    /// Public key for this signer
+    public: ed25519_dalek::PublicKey,
+
+    /// Secret key expanded once at construction, avoiding re-hashing the
+    /// seed for every signature
+    expanded: ExpandedSecretKey,
+
+    /// Whether the secret key has been scrubbed by `zeroize_secret`, after
+    /// which this signer can no longer sign
+    disabled: bool,
+}
+
+impl Ed25519Signer {
+    /// Generate a new random Ed25519 signer using the given cryptographically
+    /// secure random number generator
+    #[cfg(feature = "rand")]
+    pub fn generate<R>(csprng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        Self::from_keypair(Keypair::generate(csprng))
+    }
+
+    /// Create a new Ed25519 signer from an unexpanded seed value, returning
+    /// `KeyInvalid` if ed25519-dalek rejects the seed
+    pub fn from_seed(seed: &ed25519::Seed) -> Result<Self, Error> {
+        keypair_from_seed(seed.as_secret_slice()).map(Self::from_keypair)
+    }
+
+    /// Create a new Ed25519 signer from a seed as in `from_seed`, first
+    /// returning `KeyInvalid` if the seed is all zeroes or has fewer than
+    /// `MIN_SEED_HAMMING_WEIGHT` bits set.
+    ///
+    /// This is a guardrail against seeds read from uninitialized or zeroed
+    /// memory, not a security guarantee: it cannot detect a seed which is
+    /// predictable but has a normal-looking bit distribution. A uniformly
+    /// random seed has on average 128 bits set, and falls below the threshold
+    /// with negligible probability.
+    pub fn from_seed_checked(seed: &ed25519::Seed) -> Result<Self, Error> {
+        let weight: u32 = seed
+            .as_secret_slice()
+            .iter()
+            .map(|byte| byte.count_ones())
+            .sum();
+
+        if weight < MIN_SEED_HAMMING_WEIGHT {
+            return Err(Error::new(
+                ErrorKind::KeyInvalid,
+                Some("seed has suspiciously low entropy"),
+            ));
+        }
+
+        Self::from_seed(seed)
+    }
+
+    /// Create a new Ed25519 signer from a seed and the public key it is
+    /// expected to correspond to (e.g. when they are stored separately),
+    /// returning `KeyInvalid` if the public key does not match the seed
+    pub fn from_seed_and_public_key(
+        seed: &ed25519::Seed,
+        public_key: &ed25519::PublicKey,
+    ) -> Result<Self, Error> {
+        let signer = Self::from_seed(seed)?;
+        signer.verify_public_key(public_key)?;
+        Ok(signer)
+    }
+
+    /// Create a new Ed25519 signer from a 64-byte keypair (i.e. a seed
+    /// followed by its compressed Edwards-y public key), returning
+    /// `KeyInvalid` if the keypair is malformed or the public key does not
+    /// correspond to the seed
+    pub fn from_keypair_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let keypair = Keypair::from_bytes(bytes).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+        checked_public_key(&keypair)?;
+        Ok(Self::from_keypair(keypair))
+    }
+
+    /// Create a new Ed25519 signer from a 64-byte expanded secret key, i.e.
+    /// the clamped secret scalar followed by the 32-byte nonce prefix, as
+    /// produced by hashing a seed with SHA-512 (RFC 8032 Section 5.1.5).
+    ///
+    /// This bypasses the seed expansion step, e.g. for secure enclaves which
+    /// only export expanded keys. The scalar must already be clamped (its
+    /// three lowest bits cleared, its highest bit cleared and its second
+    /// highest bit set): `KeyInvalid` is returned otherwise.
+    ///
+    /// The seed of such a signer is unrecoverable, so it cannot be exported
+    /// via `to_seed` or any of the serialization methods built on it.
+    pub fn from_expanded_secret(bytes: &[u8; 64]) -> Result<Self, Error> {
+        if bytes[0] & 0b111 != 0 || bytes[31] & 0b1100_0000 != 0b0100_0000 {
+            return Err(Error::new(
+                ErrorKind::KeyInvalid,
+                Some("expanded secret scalar is not clamped"),
+            ));
+        }
+
+        let expanded =
+            ExpandedSecretKey::from_bytes(bytes).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
+
+        Ok(Ed25519Signer {
+            secret: None,
+            public: ed25519_dalek::PublicKey::from(&expanded),
+            expanded,
+            disabled: false,
+        })
+    }
+
+    /// Serialize this signer as a 64-byte keypair (i.e. the seed followed by
+    /// its compressed Edwards-y public key), loadable via `from_keypair_bytes`.
+    /// Returns `KeyInvalid` if the signer has no seed (see `to_seed`).
+    ///
+    /// The returned array contains secret key material: the caller is
+    /// responsible for zeroizing it once it is no longer needed.
+    pub fn to_keypair_bytes(&self) -> Result<[u8; 64], Error> {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(self.seed()?.as_bytes());
+        bytes[32..].copy_from_slice(self.public.as_bytes());
+        Ok(bytes)
+    }
+
+    /// Obtain the seed for this signer, returning `KeyInvalid` if it was
+    /// constructed with `from_expanded_secret` and therefore has none.
+    ///
+    /// Unlike the raw bytes returned by `to_keypair_bytes`, the `Seed` type
+    /// zeroizes itself when dropped.
+    pub fn to_seed(&self) -> Result<ed25519::Seed, Error> {
+        Ok(ed25519::Seed::new(self.seed()?.to_bytes()))
+    }
+
+    /// Check that this signer's public key (derived from its secret key) is the
+    /// `expected` one, returning `KeyInvalid` if it is not. Useful for
+    /// catching swapped or mislabeled key files at load time.
+    ///
+    /// The comparison is performed in constant time.
+    pub fn verify_public_key(&self, expected: &ed25519::PublicKey) -> Result<(), Error> {
+        let derived = ed25519_dalek::PublicKey::from(&self.expanded);
+
+        if ct_eq_bytes(derived.as_bytes(), expected.as_bytes()) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorKind::KeyInvalid,
+                Some("public key does not match expected value"),
+            ))
+        }
+    }
+
+    /// Create a verifier for this signer's public key directly from the
+    /// decoded key, without re-parsing its encoding
+    pub fn verifier(&self) -> Ed25519Verifier {
+        Ed25519Verifier(self.public)
+    }
+
+    /// Serialize this signer in its canonical compact binary form: the 32-byte
+    /// seed, from which the public key is re-derived by `deserialize`. This
+    /// format is stable across crate versions. Returns `KeyInvalid` if the
+    /// signer has no seed (see `to_seed`).
+    ///
+    /// The returned array contains secret key material: the caller is
+    /// responsible for zeroizing it once it is no longer needed.
+    pub fn serialize(&self) -> Result<[u8; ed25519::SEED_SIZE], Error> {
+        Ok(self.seed()?.to_bytes())
+    }
+
+    /// Deserialize a signer from the canonical compact binary form produced
+    /// by `serialize`, returning `KeyInvalid` if ed25519-dalek rejects the
+    /// seed
+    pub fn deserialize(bytes: &[u8; ed25519::SEED_SIZE]) -> Result<Self, Error> {
+        keypair_from_seed(bytes).map(Self::from_keypair)
+    }
+
+    /// Sign each of the given messages, collecting the resulting signatures
+    /// in the same order as the messages
+    #[cfg(feature = "alloc")]
+    pub fn sign_batch(&self, messages: &[&[u8]]) -> Result<Vec<ed25519::Signature>, Error> {
+        messages.iter().map(|msg| self.sign_message(msg)).collect()
+    }
+
+    /// Sign a message, returning the signature together with this signer's
+    /// public key, e.g. for self-describing envelopes which carry both
+    pub fn sign_with_public_key(
+        &self,
+        msg: &[u8],
+    ) -> Result<(ed25519::PublicKey, ed25519::Signature), Error> {
+        let signature = self.sign_message(msg)?;
+        Ok((self.public_key()?, signature))
+    }
+
+    /// Create an Ed25519ph signer (with no context) sharing this signer's
+    /// key, returning `KeyInvalid` if the signer has no seed (see `to_seed`)
+    pub fn to_prehash_signer(&self) -> Result<Ed25519PhSigner, Error> {
+        let keypair = Keypair {
+            secret: SecretKey::from_bytes(self.seed()?.as_bytes()).unwrap(),
+            public: self.public,
+        };
+
+        Ok(Ed25519PhSigner {
+            keypair,
+            context: None,
+        })
+    }
+
+    /// Borrow the seed for this signer, if it has one
+    fn seed(&self) -> Result<&SecretKey, Error> {
+        self.secret.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::KeyInvalid,
+                Some("signer was constructed from an expanded secret key and has no seed"),
+            )
+        })
+    }
+
+    /// Scrub this signer's secret key from memory, after which it can no
+    /// longer sign: all subsequent signing operations return
+    /// `ProviderError`.
+    ///
+    /// Unlike dropping the signer, this leaves the (disabled) signer in place,
+    /// e.g. in a pool slot.
+    #[cfg(feature = "zeroize")]
+    pub fn zeroize_secret(&mut self) {
+        // ed25519-dalek overwrites its secret key types when they are dropped
+        self.secret = None;
+        self.expanded = ExpandedSecretKey::from_bytes(&[0u8; 64]).unwrap();
+        self.disabled = true;
+    }
+
+    /// Return `ProviderError` if the secret key has been scrubbed by
+    /// `zeroize_secret`
+    fn ensure_enabled(&self) -> Result<(), Error> {
+        if self.disabled {
+            return Err(Error::new(
+                ErrorKind::ProviderError,
+                Some("signer's secret key has been zeroized"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sign a message using the expanded secret key, returning
+    /// `ProviderError` if it has been scrubbed by `zeroize_secret`
+    fn sign_message(&self, msg: &[u8]) -> Result<ed25519::Signature, Error> {
+        #[cfg(feature = "tracing")]
+        let span = spans::sign_span(&self.public, msg.len()).entered();
+
+        let result = self.ensure_enabled().map(|()| {
+            #[cfg(feature = "log")]
+            logging::log_sign(&self.public, msg.len());
+
+            ed25519::Signature::new(self.expanded.sign(msg, &self.public).to_bytes())
+        });
+
+        #[cfg(feature = "tracing")]
+        spans::record_outcome(&span, &result);
+
+        result
+    }
+
+    /// Create a signer from a Dalek keypair, expanding its secret key
+    fn from_keypair(keypair: Keypair) -> Self {
+        // Callers must have derived or checked the public key, so it can be
+        // returned by `public_key` without re-deriving it on every call
+        debug_assert!(
+            ed25519_dalek::PublicKey::from(&keypair.secret) == keypair.public,
+            "public key does not match seed"
+        );
+
+        Ed25519Signer {
+            expanded: ExpandedSecretKey::from(&keypair.secret),
+            secret: Some(keypair.secret),
+            public: keypair.public,
+            disabled: false,
+        }
+    }
+}
+
+impl Clone for Ed25519Signer {
+    fn clone(&self) -> Self {
+        Ed25519Signer {
+            secret: self
+                .secret
+                .as_ref()
+                .map(|secret| SecretKey::from_bytes(secret.as_bytes()).unwrap()),
+            public: self.public,
+            expanded: ExpandedSecretKey::from_bytes(&self.expanded.to_bytes()).unwrap(),
+            disabled: self.disabled,
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Ed25519Signer {
+    /// Scrub this signer's secret key, as with `zeroize_secret`
+    fn zeroize(&mut self) {
+        self.zeroize_secret();
+    }
+}
+
+impl Debug for Ed25519Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ed25519Signer {{ public_key: ")?;
+        fmt_hex(f, self.public.as_bytes())?;
+        write!(f, ", secret: [REDACTED] }}")
+    }
+}
 
 impl<'a> From<&'a ed25519::Seed> for Ed25519Signer {
     /// Create a new DalekSigner from an unexpanded seed value
     fn from(seed: &'a ed25519::Seed) -> Self {
-        Ed25519Signer(keypair_from_seed(seed))
+        // `ed25519::Seed` is always 32 bytes, which is all dalek checks for
+        Self::from_seed(seed).unwrap()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Ed25519Signer {
+    type Error = Error;
+
+    /// Create a new Ed25519 signer from a 32-byte seed, returning
+    /// `KeyInvalid` if the slice is the wrong length
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Error> {
+        Self::from_seed(&ed25519::Seed::from_bytes(bytes)?)
     }
 }
 
 impl PublicKeyed<ed25519::PublicKey> for Ed25519Signer {
     fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
-        Ok(ed25519::PublicKey::from_bytes(self.0.public.as_bytes()).unwrap())
+        Ok(ed25519::PublicKey::new(*self.public.as_bytes()))
     }
 }
 
 impl Signer<ed25519::Signature> for Ed25519Signer {
     fn sign(&self, msg: &[u8]) -> Result<ed25519::Signature, Error> {
-        let signature = self.0.sign(msg).to_bytes();
-        Ok(Signature::from_bytes(&signature[..]).unwrap())
+        self.sign_message(msg)
     }
 }
 
 /// Ed25519ph (i.e. pre-hashed) signature provider for ed25519-dalek
-pub struct Ed25519PhSigner(Keypair);
+pub struct Ed25519PhSigner {
+    keypair: Keypair,
+    context: Option<Context>,
+}
+
+impl Ed25519PhSigner {
+    /// Create a new Ed25519ph signer which binds its signatures to the given
+    /// context string (RFC 8032 Section 5.1), returning `KeyInvalid` if
+    /// ed25519-dalek rejects the seed
+    pub fn new_with_context(seed: &ed25519::Seed, context: Context) -> Result<Self, Error> {
+        Ok(Ed25519PhSigner {
+            keypair: keypair_from_seed(seed.as_secret_slice())?,
+            context: Some(context),
+        })
+    }
+
+    /// Sign the given message with Ed25519ph, hashing it with SHA-512
+    pub fn sign_message(&self, msg: &[u8]) -> Result<ed25519::Signature, Error> {
+        self.sign_message_with::<DefaultPhDigest>(msg)
+    }
+
+    /// Sign the given message with Ed25519ph, hashing it with the digest `D`
+    pub fn sign_message_with<D>(&self, msg: &[u8]) -> Result<ed25519::Signature, Error>
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        self.sign(D::new().chain(msg))
+    }
+
+    /// Sign a message consisting of the concatenation of the given segments
+    /// (e.g. a header followed by a body) with Ed25519ph, without copying
+    /// them into a single buffer
+    pub fn sign_segments(&self, segments: &[&[u8]]) -> Result<ed25519::Signature, Error> {
+        self.sign_segments_with::<DefaultPhDigest>(segments)
+    }
+
+    /// Sign a message consisting of the concatenation of the given segments
+    /// with Ed25519ph, hashing them with the digest `D`
+    pub fn sign_segments_with<D>(&self, segments: &[&[u8]]) -> Result<ed25519::Signature, Error>
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        let mut digest = D::new();
+
+        for segment in segments {
+            digest.input(segment);
+        }
+
+        self.sign(digest)
+    }
+
+    /// Sign an already-computed 64-byte SHA-512 prehash of a message with
+    /// Ed25519ph, returning `ParseError` if it is the wrong length.
+    ///
+    /// This allows the same prehash to be reused for both signing and
+    /// verification without hashing the message twice.
+    pub fn sign_prehash(&self, prehash: &[u8]) -> Result<ed25519::Signature, Error> {
+        self.sign(Prehash::new(prehash)?)
+    }
+
+    /// Sign the given digest with Ed25519ph under the given context string,
+    /// rather than this signer's own context, returning `ContextInvalid` if
+    /// the context is longer than `MAX_CONTEXT_SIZE`.
+    ///
+    /// Unlike `Context`, the context string need not be `'static`, so it can
+    /// be built at runtime, e.g. from a per-request identifier.
+    pub fn sign_with_context<D>(
+        &self,
+        digest: D,
+        context: &[u8],
+    ) -> Result<ed25519::Signature, Error>
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        context::check_size(context)?;
+
+        #[cfg(feature = "log")]
+        logging::log_sign_prehashed(&self.keypair.public);
+
+        Ok(dom2::sign_prehashed(
+            &self.keypair,
+            digest.result().as_slice(),
+            context,
+        ))
+    }
+
+    /// Sign the given digest with Ed25519ph under the given context
+    fn sign_digest<D>(&self, digest: D, context: Option<Context>) -> ed25519::Signature
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        #[cfg(feature = "log")]
+        logging::log_sign_prehashed(&self.keypair.public);
+
+        let signature = self
+            .keypair
+            .sign_prehashed(digest, context.map(|ctx| ctx.as_bytes()));
+
+        ed25519::Signature::new(signature.to_bytes())
+    }
+
+    /// Create a pure Ed25519 signer sharing this signer's key. The context
+    /// (if any) is not carried over, as pure Ed25519 does not support one.
+    pub fn to_signer(&self) -> Ed25519Signer {
+        Ed25519Signer::from_keypair(clone_keypair(&self.keypair))
+    }
+}
+
+impl Clone for Ed25519PhSigner {
+    fn clone(&self) -> Self {
+        Ed25519PhSigner {
+            keypair: clone_keypair(&self.keypair),
+            context: self.context,
+        }
+    }
+}
+
+impl Debug for Ed25519PhSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ed25519PhSigner {{ public_key: ")?;
+        fmt_hex(f, self.keypair.public.as_bytes())?;
+        write!(f, ", secret: [REDACTED] }}")
+    }
+}
 
 impl<'a> From<&'a ed25519::Seed> for Ed25519PhSigner {
     /// Create a new DalekSigner from an unexpanded seed value
     fn from(seed: &'a ed25519::Seed) -> Self {
-        Ed25519PhSigner(keypair_from_seed(seed))
+        Ed25519PhSigner {
+            keypair: keypair_from_seed(seed.as_secret_slice()).unwrap(),
+            context: None,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Ed25519PhSigner {
+    type Error = Error;
+
+    /// Create a new Ed25519ph signer from a 32-byte seed, returning
+    /// `KeyInvalid` if the slice is the wrong length
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Error> {
+        let seed = ed25519::Seed::from_bytes(bytes)?;
+
+        Ok(Ed25519PhSigner {
+            keypair: keypair_from_seed(seed.as_secret_slice())?,
+            context: None,
+        })
     }
 }
 
 impl PublicKeyed<ed25519::PublicKey> for Ed25519PhSigner {
     fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
-        Ok(ed25519::PublicKey::from_bytes(self.0.public.as_bytes()).unwrap())
+        checked_public_key(&self.keypair)
     }
 }
 
-// TODO: tests!
 impl<D> DigestSigner<D, ed25519::Signature> for Ed25519PhSigner
 where
     D: Digest<OutputSize = U64> + Default,
 {
     fn sign(&self, digest: D) -> Result<ed25519::Signature, Error> {
-        // TODO: context support
-        let context: Option<&'static [u8]> = None;
-
-        let signature =
-            Signature::from_bytes(&self.0.sign_prehashed(digest, context).to_bytes()[..]).unwrap();
-
-        Ok(signature)
+        Ok(self.sign_digest(digest, self.context))
     }
 }
 
 /// Ed25519 verifier provider for ed25519-dalek
+///
+/// This verifier accepts malleable signatures: see `Ed25519StrictVerifier`
+/// for a verifier which rejects them.
+///
+/// The derived `PartialEq` impl is provided for convenience and is not
+/// constant time: use `ct_eq` (with the `subtle` feature) when comparing
+/// against keys which must not leak timing information.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Ed25519Verifier(ed25519_dalek::PublicKey);
 
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from a public key, returning `KeyInvalid`
+    /// if it does not decode to a valid Edwards point
+    pub fn from_public_key(public_key: &ed25519::PublicKey) -> Result<Self, Error> {
+        decode_public_key(public_key.as_ref()).map(Ed25519Verifier)
+    }
+
+    /// Create a new Ed25519 verifier from a public key as in `from_public_key`,
+    /// additionally returning `KeyInvalid` if it is one of the eight
+    /// small-order points (i.e. the identity or a torsion point).
+    ///
+    /// Signatures under a small-order public key can be valid for many
+    /// messages at once, which some protocols must guard against.
+    pub fn from_public_key_checked(public_key: &ed25519::PublicKey) -> Result<Self, Error> {
+        decode_public_key_checked(public_key.as_ref()).map(Ed25519Verifier)
+    }
+
+    /// Create a new Ed25519 verifier from the bytes of a compressed Edwards-y
+    /// public key, returning `ParseError` if they are not 32 bytes long, or
+    /// `KeyInvalid` if they are not the canonical encoding of a valid Edwards
+    /// point (e.g. if the `y` coordinate is not less than the field prime
+    /// `p = 2^255 - 19`).
+    ///
+    /// Unlike `from_public_key`, which accepts any encoding ed25519-dalek
+    /// decodes, this rejects non-canonical encodings.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        decode_public_key_canonical(bytes).map(Ed25519Verifier)
+    }
+
+    /// Create a new Ed25519 verifier from the bytes of a compressed Edwards-y
+    /// public key, returning `KeyInvalid` if its 255-bit `y` coordinate is not
+    /// less than the field prime `p = 2^255 - 19` (or if it is otherwise not
+    /// the canonical encoding of a valid Edwards point).
+    ///
+    /// This currently accepts exactly the same keys as `from_bytes`, but
+    /// guarantees it will keep rejecting non-canonical encodings, for
+    /// consensus systems which must agree on exactly which keys are valid.
+    pub fn from_bytes_canonical(bytes: &[u8]) -> Result<Self, Error> {
+        decode_public_key_canonical(bytes).map(Ed25519Verifier)
+    }
+
+    /// Create a new Ed25519 verifier from a compressed Edwards-y point, i.e.
+    /// the standard Ed25519 public key encoding, returning `KeyInvalid` if it
+    /// is not a valid point.
+    ///
+    /// Ristretto255 encodings (as used by schnorrkel/sr25519) are not
+    /// accepted: if the bytes are not a valid Edwards point but are a valid
+    /// Ristretto encoding, the error says so. Bytes which are valid under
+    /// both encodings cannot be told apart, and are decoded as Edwards.
+    pub fn from_edwards_compressed(bytes: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, Error> {
+        Self::from_bytes(bytes).map_err(|e| {
+            if CompressedRistretto(*bytes).decompress().is_some() {
+                Error::new(
+                    ErrorKind::KeyInvalid,
+                    Some("expected a compressed Edwards point, got a Ristretto encoding"),
+                )
+            } else {
+                e
+            }
+        })
+    }
+
+    /// Borrow the underlying ed25519-dalek public key.
+    ///
+    /// This is an escape hatch for APIs not wrapped by this crate: relying on
+    /// it couples the caller to the ed25519-dalek version used by this crate.
+    pub fn as_dalek(&self) -> &ed25519_dalek::PublicKey {
+        &self.0
+    }
+
+    /// Convert this verifier's Edwards public key into the equivalent X25519
+    /// (Montgomery u-coordinate) public key via the birational map
+    /// `u = (1 + y) / (1 - y)`, as in libsodium's
+    /// `crypto_sign_ed25519_pk_to_curve25519`
+    pub fn to_x25519_bytes(&self) -> [u8; 32] {
+        // Public keys are validated as points on the curve on construction
+        CompressedEdwardsY(*self.0.as_bytes())
+            .decompress()
+            .unwrap()
+            .to_montgomery()
+            .to_bytes()
+    }
+
+    /// Verify a signature over a message as with `Verifier::verify`, first
+    /// rejecting messages longer than `max_len` bytes with `ParseError`
+    /// before doing any cryptographic work
+    pub fn verify_bounded(
+        &self,
+        msg: &[u8],
+        sig: &ed25519::Signature,
+        max_len: usize,
+    ) -> Result<(), Error> {
+        if msg.len() > max_len {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("message exceeds maximum length"),
+            ));
+        }
+
+        self.verify(msg, sig)
+    }
+
+    /// Verify each of the given signatures over its paired message, stopping
+    /// at (and returning `SignatureInvalid` for) the first which does not
+    /// verify.
+    ///
+    /// The public key is decompressed once when this verifier is constructed
+    /// and the decoded point reused for every signature, so this only saves
+    /// the per-call overhead of `Verifier::verify`. See `verify_batch` (with
+    /// the `batch` feature) for faster verification of many signatures.
+    pub fn verify_many(&self, items: &[(&[u8], ed25519::Signature)]) -> Result<(), Error> {
+        for (msg, sig) in items {
+            self.verify(msg, sig)?;
+        }
+
+        Ok(())
+    }
+
+    /// Is the given signature over the message valid under this verifier's
+    /// public key? This is `Verifier::verify` without the `Result`: malformed
+    /// signatures are reported as invalid, and the errors `verify` produces
+    /// carry no message, so this never allocates.
+    pub fn is_valid(&self, msg: &[u8], sig: &ed25519::Signature) -> bool {
+        self.verify(msg, sig).is_ok()
+    }
+
+    /// Verify a signature over a message as with `Verifier::verify`,
+    /// returning the message on success so verification can be chained in a
+    /// pipeline
+    pub fn verify_then<'a>(
+        &self,
+        msg: &'a [u8],
+        sig: &ed25519::Signature,
+    ) -> Result<&'a [u8], Error> {
+        self.verify(msg, sig).map(|()| msg)
+    }
+
+    /// Verify a signature over a message as with `Verifier::verify`, retrying
+    /// against this verifier's public key with the sign bit of its `x`
+    /// coordinate flipped (i.e. the negated point) if that fails.
+    ///
+    /// **This is a compatibility hack** for interoperating with software
+    /// which mis-encodes public keys this way, and should only be used for
+    /// as long as signatures from such software must still be accepted: it
+    /// doubles the cost of rejecting invalid signatures, and accepts
+    /// signatures made under a key other than this one.
+    pub fn verify_compat(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        if self.verify(msg, sig).is_ok() {
+            return Ok(());
+        }
+
+        let mut flipped = *self.0.as_bytes();
+        flipped[31] ^= 0x80;
+
+        let flipped_key = ed25519_dalek::PublicKey::from_bytes(&flipped)
+            .map_err(|_| Error::from(ErrorKind::SignatureInvalid))?;
+
+        flipped_key
+            .verify(msg, &decode_signature(sig)?)
+            .map_err(|_| ErrorKind::SignatureInvalid.into())
+    }
+
+    /// Verify a signature whose `s` scalar (its last 32 bytes) was encoded
+    /// big-endian instead of little-endian, by reversing those bytes before
+    /// verifying as with `Verifier::verify`.
+    ///
+    /// **This is a diagnostic and migration tool only**, for telling whether
+    /// (and bridging while) an implementation byte-swaps `s`: correctly
+    /// encoded signatures are rejected by it, and it should not be used once
+    /// the signing side has been fixed.
+    pub fn verify_byteswapped_s(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        let mut bytes = [0u8; SIGNATURE_LENGTH];
+        bytes.copy_from_slice(sig.as_ref());
+        bytes[32..].reverse();
+        self.verify(msg, &ed25519::Signature::new(bytes))
+    }
+
+    /// Compute a fingerprint of this verifier's public key, i.e. the SHA-256
+    /// digest of its 32-byte compressed Edwards-y encoding
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut fingerprint = [0u8; 32];
+        fingerprint.copy_from_slice(&Sha256::digest(self.0.as_bytes()));
+        fingerprint
+    }
+
+    /// Compute the fingerprint of this verifier's public key as lower-case hex
+    #[cfg(feature = "alloc")]
+    pub fn fingerprint_hex(&self) -> String {
+        Hex::default()
+            .encode_to_string(&self.fingerprint()[..])
+            .unwrap()
+    }
+
+    /// Check whether the given bytes are this verifier's compressed Edwards-y
+    /// public key, e.g. for membership checks against keys received over the
+    /// wire without decoding them into a second verifier.
+    ///
+    /// Returns `false` if `bytes` is not 32 bytes long. The comparison is
+    /// performed in constant time with respect to the contents of `bytes`.
+    pub fn matches_bytes(&self, bytes: &[u8]) -> bool {
+        ct_eq_bytes(self.0.as_bytes(), bytes)
+    }
+
+    /// Compare the public keys of two verifiers in constant time
+    #[cfg(feature = "subtle")]
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_bytes().ct_eq(other.0.as_bytes())
+    }
+
+    /// Create a new Ed25519 verifier from a public key encoded as exactly 64
+    /// lower-case hexadecimal characters.
+    ///
+    /// Upper-case and `0x`-prefixed strings are rejected with `ParseError`,
+    /// matching the output of this type's `Display` impl. Strings which decode
+    /// to an invalid Edwards point are rejected with `KeyInvalid`.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let mut bytes = [0u8; ed25519::PUBLIC_KEY_SIZE];
+
+        if hex.len() != bytes.len() * 2
+            || Hex::default()
+                .decode_to_slice(hex.as_bytes(), &mut bytes)
+                .is_err()
+        {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("expected 64 lower-case hex characters"),
+            ));
+        }
+
+        decode_public_key(&bytes).map(Ed25519Verifier)
+    }
+}
+
+impl AsRef<[u8]> for Ed25519Verifier {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl Hash for Ed25519Verifier {
+    /// Hash the compressed public key, consistently with `PartialEq`
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_bytes().hash(state);
+    }
+}
+
+impl PartialOrd for Ed25519Verifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ed25519Verifier {
+    /// Compare the compressed public keys lexicographically, e.g. to give sets
+    /// of verifiers a stable order
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_bytes().cmp(other.0.as_bytes())
+    }
+}
+
+/// Displays the public key as lower-case hexadecimal
+impl Display for Ed25519Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(f, self.0.as_bytes())
+    }
+}
+
+impl FromStr for Ed25519Verifier {
+    type Err = Error;
+
+    fn from_str(hex: &str) -> Result<Self, Error> {
+        Self::from_hex(hex)
+    }
+}
+
+impl TryFrom<[u8; ed25519::PUBLIC_KEY_SIZE]> for Ed25519Verifier {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; ed25519::PUBLIC_KEY_SIZE]) -> Result<Self, Error> {
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Deprecated: prefer `Ed25519Verifier::from_public_key`, which returns
+/// `KeyInvalid` instead of panicking. This impl will be removed in a future
+/// release.
+///
+/// # Panics
+///
+/// Panics if the public key does not decode to a valid Edwards point.
+// `#[deprecated]` cannot be applied to trait impls, so this is documented only
 impl<'a> From<&'a ed25519::PublicKey> for Ed25519Verifier {
     fn from(public_key: &'a ed25519::PublicKey) -> Self {
-        Ed25519Verifier(ed25519_dalek::PublicKey::from_bytes(public_key.as_ref()).unwrap())
+        Self::from_public_key(public_key).unwrap()
     }
 }
 
 impl Verifier<ed25519::Signature> for Ed25519Verifier {
     fn verify(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
-        let dalek_sig = ed25519_dalek::Signature::from_bytes(sig.as_ref()).unwrap();
-        self.0
-            .verify(msg, &dalek_sig)
-            .map_err(|_| ErrorKind::SignatureInvalid.into())
+        #[cfg(feature = "tracing")]
+        let span = spans::verify_span(&self.0, msg.len()).entered();
+
+        let result = decode_signature(sig).and_then(|dalek_sig| {
+            self.0
+                .verify(msg, &dalek_sig)
+                .map_err(|_| ErrorKind::SignatureInvalid.into())
+        });
+
+        #[cfg(feature = "tracing")]
+        spans::record_outcome(&span, &result);
+
+        result
     }
 }
 
 /// Ed25519ph (i.e. pre-hashed) verifier provider for ed25519-dalek
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Ed25519PhVerifier(ed25519_dalek::PublicKey);
+pub struct Ed25519PhVerifier {
+    public_key: ed25519_dalek::PublicKey,
+    context: Option<Context>,
+}
+
+impl Ed25519PhVerifier {
+    /// Create a new Ed25519ph verifier from a public key, returning
+    /// `KeyInvalid` if it does not decode to a valid Edwards point
+    pub fn from_public_key(public_key: &ed25519::PublicKey) -> Result<Self, Error> {
+        Ok(Ed25519PhVerifier {
+            public_key: decode_public_key(public_key.as_ref())?,
+            context: None,
+        })
+    }
+
+    /// Create a new Ed25519ph verifier from a public key as in
+    /// `from_public_key`, additionally returning `KeyInvalid` if it is one of
+    /// the eight small-order points (i.e. the identity or a torsion point)
+    pub fn from_public_key_checked(public_key: &ed25519::PublicKey) -> Result<Self, Error> {
+        Ok(Ed25519PhVerifier {
+            public_key: decode_public_key_checked(public_key.as_ref())?,
+            context: None,
+        })
+    }
+
+    /// Create a new Ed25519ph verifier from the bytes of a compressed
+    /// Edwards-y public key, returning `ParseError` if they are not 32 bytes
+    /// long, or `KeyInvalid` if they are not the canonical encoding of a valid
+    /// Edwards point (unlike `from_public_key`)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Ed25519PhVerifier {
+            public_key: decode_public_key_canonical(bytes)?,
+            context: None,
+        })
+    }
+
+    /// Borrow the underlying ed25519-dalek public key.
+    ///
+    /// This is an escape hatch for APIs not wrapped by this crate: relying on
+    /// it couples the caller to the ed25519-dalek version used by this crate.
+    pub fn as_dalek(&self) -> &ed25519_dalek::PublicKey {
+        &self.public_key
+    }
+
+    /// Create a new Ed25519ph verifier which expects signatures to be bound
+    /// to the given context string (RFC 8032 Section 5.1), returning
+    /// `KeyInvalid` if the public key does not decode to a valid Edwards point
+    pub fn from_public_key_with_context(
+        public_key: &ed25519::PublicKey,
+        context: Context,
+    ) -> Result<Self, Error> {
+        Ok(Ed25519PhVerifier {
+            public_key: decode_public_key(public_key.as_ref())?,
+            context: Some(context),
+        })
+    }
+
+    /// Verify an Ed25519ph signature over the given message, hashing it with
+    /// SHA-512
+    pub fn verify_message(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        self.verify_message_with::<DefaultPhDigest>(msg, sig)
+    }
 
+    /// Verify an Ed25519ph signature over the given message, hashing it with
+    /// the digest `D`
+    pub fn verify_message_with<D>(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error>
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        self.verify(D::new().chain(msg), sig)
+    }
+
+    /// Verify an Ed25519ph signature over an already-computed 64-byte SHA-512
+    /// prehash of a message, returning `ParseError` if it is the wrong length
+    pub fn verify_prehash(&self, prehash: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        self.verify(Prehash::new(prehash)?, sig)
+    }
+}
+
+impl AsRef<[u8]> for Ed25519PhVerifier {
+    fn as_ref(&self) -> &[u8] {
+        self.public_key.as_bytes()
+    }
+}
+
+impl Hash for Ed25519PhVerifier {
+    /// Hash the compressed public key and context, consistently with
+    /// `PartialEq`
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.public_key.as_bytes().hash(state);
+        self.context.hash(state);
+    }
+}
+
+impl TryFrom<[u8; ed25519::PUBLIC_KEY_SIZE]> for Ed25519PhVerifier {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; ed25519::PUBLIC_KEY_SIZE]) -> Result<Self, Error> {
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Deprecated: prefer `Ed25519PhVerifier::from_public_key`, which returns
+/// `KeyInvalid` instead of panicking. This impl will be removed in a future
+/// release.
+///
+/// # Panics
+///
+/// Panics if the public key does not decode to a valid Edwards point.
+// `#[deprecated]` cannot be applied to trait impls, so this is documented only
 impl<'a> From<&'a ed25519::PublicKey> for Ed25519PhVerifier {
     fn from(public_key: &'a ed25519::PublicKey) -> Self {
-        Ed25519PhVerifier(ed25519_dalek::PublicKey::from_bytes(public_key.as_ref()).unwrap())
+        Self::from_public_key(public_key).unwrap()
     }
 }
 
-// TODO: tests!
 impl<D> DigestVerifier<D, ed25519::Signature> for Ed25519PhVerifier
 where
     D: Digest<OutputSize = U64> + Default,
 {
     fn verify(&self, digest: D, sig: &ed25519::Signature) -> Result<(), Error> {
-        // TODO: context support
-        let context: Option<&'static [u8]> = None;
-        let dalek_sig = ed25519_dalek::Signature::from_bytes(sig.as_ref()).unwrap();
-        self.0
-            .verify_prehashed(digest, context, &dalek_sig)
+        let dalek_sig = decode_signature(sig)?;
+        self.public_key
+            .verify_prehashed(digest, self.context.map(|ctx| ctx.as_bytes()), &dalek_sig)
             .map_err(|_| ErrorKind::SignatureInvalid.into())
     }
 }
 
-/// Convert a Signatory seed into a Dalek keypair
-fn keypair_from_seed(seed: &ed25519::Seed) -> Keypair {
-    let secret = SecretKey::from_bytes(seed.as_secret_slice()).unwrap();
+/// Convert the bytes of a Signatory seed into a Dalek keypair
+fn keypair_from_seed(seed: &[u8]) -> Result<Keypair, Error> {
+    let secret = SecretKey::from_bytes(seed).map_err(|_| Error::from(ErrorKind::KeyInvalid))?;
     let public = ed25519_dalek::PublicKey::from(&secret);
-    Keypair { secret, public }
+    Ok(Keypair { secret, public })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{Ed25519Signer, Ed25519Verifier};
-    ed25519_tests!(Ed25519Signer, Ed25519Verifier);
+/// Clone a Dalek keypair (which does not itself impl `Clone`) by re-deriving
+/// it from the bytes of its secret key
+fn clone_keypair(keypair: &Keypair) -> Keypair {
+    Keypair {
+        secret: SecretKey::from_bytes(keypair.secret.as_bytes()).unwrap(),
+        public: keypair.public,
+    }
+}
+
+/// Obtain the Signatory public key for a Dalek keypair, returning `KeyInvalid`
+/// if its public key does not correspond to its secret key
+fn checked_public_key(keypair: &Keypair) -> Result<ed25519::PublicKey, Error> {
+    if ed25519_dalek::PublicKey::from(&keypair.secret) != keypair.public {
+        return Err(Error::new(
+            ErrorKind::KeyInvalid,
+            Some("public key does not match seed"),
+        ));
+    }
+
+    ed25519::PublicKey::from_bytes(keypair.public.as_bytes())
+}
+
+/// Overwrite a secret scalar with zero
+fn clear_scalar(scalar: &mut Scalar) {
+    *scalar = Scalar::zero();
+}
+
+/// Overwrite secret bytes with zeroes, using volatile writes which cannot be
+/// optimized away when the `zeroize` feature is enabled
+fn clear_bytes(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+
+    #[cfg(not(feature = "zeroize"))]
+    for byte in bytes.iter_mut() {
+        *byte = 0;
+    }
+}
+
+/// Compare two byte slices in constant time with respect to their contents
+fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Decode the bytes of a Signatory public key into a Dalek public key,
+/// returning `ParseError` if they are the wrong length or `KeyInvalid` if Dalek
+/// rejects them
+fn decode_public_key(bytes: &[u8]) -> Result<ed25519_dalek::PublicKey, Error> {
+    if bytes.len() != ed25519::PUBLIC_KEY_SIZE {
+        return Err(Error::new(
+            ErrorKind::ParseError,
+            Some("public key must be 32 bytes"),
+        ));
+    }
+
+    ed25519_dalek::PublicKey::from_bytes(bytes).map_err(|_| Error::from(ErrorKind::KeyInvalid))
+}
+
+/// Decode the bytes of a Signatory public key as in `decode_public_key`,
+/// additionally returning `KeyInvalid` unless they are the canonical encoding
+/// of a valid Edwards point
+fn decode_public_key_canonical(bytes: &[u8]) -> Result<ed25519_dalek::PublicKey, Error> {
+    let public_key = decode_public_key(bytes)?;
+
+    // Dalek accepts non-canonical encodings (e.g. `y >= p`), which do not
+    // round trip through decompression and recompression
+    let is_canonical = CompressedEdwardsY::from_slice(bytes)
+        .decompress()
+        .map(|point| point.compress().as_bytes()[..] == *bytes)
+        .unwrap_or(false);
+
+    if !is_canonical {
+        return Err(Error::new(
+            ErrorKind::KeyInvalid,
+            Some("non-canonical public key encoding"),
+        ));
+    }
+
+    Ok(public_key)
+}
+
+/// Convert a Signatory signature into a Dalek signature, returning
+/// `SignatureInvalid` if Dalek rejects it (e.g. the high bits of `s` are set)
+/// rather than panicking on untrusted input
+fn decode_signature(sig: &ed25519::Signature) -> Result<ed25519_dalek::Signature, Error> {
+    ed25519_dalek::Signature::from_bytes(sig.as_ref())
+        .map_err(|_| Error::from(ErrorKind::SignatureInvalid))
+}
+
+/// Is the given compressed Edwards-y point of small order? (or invalid)
+fn is_small_order(point: [u8; 32]) -> bool {
+    CompressedEdwardsY(point)
+        .decompress()
+        .map(|p| p.is_small_order())
+        .unwrap_or(true)
+}
+
+/// Decode the bytes of a Signatory public key into a Dalek public key as in
+/// `decode_public_key`, additionally rejecting small-order points
+fn decode_public_key_checked(bytes: &[u8]) -> Result<ed25519_dalek::PublicKey, Error> {
+    let public_key = decode_public_key(bytes)?;
+
+    if is_small_order(*public_key.as_bytes()) {
+        return Err(Error::new(
+            ErrorKind::KeyInvalid,
+            Some("public key is a small-order point"),
+        ));
+    }
+
+    Ok(public_key)
+}
+
+/// SHA-256 fingerprint of a public key, displayed as lower-case hex, for
+/// identifying keys in diagnostics without exposing anything secret
+#[cfg(any(feature = "log", feature = "tracing"))]
+struct Fingerprint([u8; 32]);
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl Fingerprint {
+    fn new(public_key: &ed25519_dalek::PublicKey) -> Self {
+        let mut fingerprint = [0u8; 32];
+        fingerprint.copy_from_slice(&Sha256::digest(public_key.as_bytes()));
+        Fingerprint(fingerprint)
+    }
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(f, &self.0)
+    }
+}
+
+/// Write the given bytes as lower-case hexadecimal
+fn fmt_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode_public_key, keypair_from_seed, Context, Ed25519PhSigner, Ed25519PhVerifier,
+        Ed25519Signer, Ed25519StrictVerifier, Ed25519Verifier, ExpandedSecretKey, Keypair,
+        SecretKey, MAX_CONTEXT_SIZE, PUBLIC_KEY_LENGTH, SEED_LENGTH, SIGNATURE_LENGTH,
+    };
+    use core::convert::TryFrom;
+    use sha2::{Digest, Sha256, Sha512};
+    use signatory::generic_array::{typenum::U64, GenericArray};
+    use signatory::{DigestSigner, DigestVerifier, PublicKeyed, Signer};
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+    ed25519_tests!(Ed25519Signer, Ed25519Verifier);
+
+    /// Non-canonical encoding of the identity point (`y = p + 1`)
+    const NON_CANONICAL_POINT: [u8; 32] = [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ];
+
+    /// Canonical encodings of the eight small-order points of Curve25519
+    const SMALL_ORDER_POINTS: [[u8; 32]; 8] = [
+        // Identity (order 1)
+        [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        // Order 2
+        [
+            0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0x7f,
+        ],
+        // Order 4
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ],
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x80,
+        ],
+        // Order 8
+        [
+            0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10,
+            0x67, 0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77,
+            0x92, 0xac, 0x03, 0x7a,
+        ],
+        [
+            0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10,
+            0x67, 0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77,
+            0x92, 0xac, 0x03, 0xfa,
+        ],
+        [
+            0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef,
+            0x98, 0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88,
+            0x6d, 0x53, 0xfc, 0x05,
+        ],
+        [
+            0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef,
+            0x98, 0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88,
+            0x6d, 0x53, 0xfc, 0x85,
+        ],
+    ];
+
+    const PH_TEST_MESSAGE: &[u8] = b"Ed25519ph context test message";
+
+    /// Ed25519ph test vector from RFC 8032 Section 7.3 ("TEST abc")
+    const RFC8032_PH_SEED: [u8; 32] = [
+        0x83, 0x3f, 0xe6, 0x24, 0x09, 0x23, 0x7b, 0x9d, 0x62, 0xec, 0x77, 0x58, 0x75, 0x20, 0x91,
+        0x1e, 0x9a, 0x75, 0x9c, 0xec, 0x1d, 0x19, 0x75, 0x5b, 0x7d, 0xa9, 0x01, 0xb9, 0x6d, 0xca,
+        0x3d, 0x42,
+    ];
+    const RFC8032_PH_PUBLIC_KEY: [u8; 32] = [
+        0xec, 0x17, 0x2b, 0x93, 0xad, 0x5e, 0x56, 0x3b, 0xf4, 0x93, 0x2c, 0x70, 0xe1, 0x24, 0x50,
+        0x34, 0xc3, 0x54, 0x67, 0xef, 0x2e, 0xfd, 0x4d, 0x64, 0xeb, 0xf8, 0x19, 0x68, 0x34, 0x67,
+        0xe2, 0xbf,
+    ];
+    const RFC8032_PH_MESSAGE: &[u8] = b"abc";
+    const RFC8032_PH_SIGNATURE: [u8; 64] = [
+        0x98, 0xa7, 0x02, 0x22, 0xf0, 0xb8, 0x12, 0x1a, 0xa9, 0xd3, 0x0f, 0x81, 0x3d, 0x68, 0x3f,
+        0x80, 0x9e, 0x46, 0x2b, 0x46, 0x9c, 0x7f, 0xf8, 0x76, 0x39, 0x49, 0x9b, 0xb9, 0x4e, 0x6d,
+        0xae, 0x41, 0x31, 0xf8, 0x50, 0x42, 0x46, 0x3c, 0x2a, 0x35, 0x5a, 0x20, 0x03, 0xd0, 0x62,
+        0xad, 0xf5, 0xaa, 0xa1, 0x0b, 0x8c, 0x61, 0xe6, 0x36, 0x06, 0x2a, 0xaa, 0xd1, 0x1c, 0x2a,
+        0x26, 0x08, 0x34, 0x06,
+    ];
+
+    /// Compressed Edwards-y encoding (`y = 2`) which does not decompress to a
+    /// point on the curve
+    const INVALID_POINT: [u8; 32] = [
+        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
+
+    /// Sign `PH_TEST_MESSAGE` under `signer_context` and verify it under
+    /// `verifier_context`
+    fn ph_context_round_trip(
+        signer_context: &'static [u8],
+        verifier_context: &'static [u8],
+    ) -> Result<(), signatory::Error> {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer =
+            Ed25519PhSigner::new_with_context(&seed, Context::new(signer_context).unwrap())
+                .unwrap();
+        let sig = signer.sign(Sha512::new().chain(PH_TEST_MESSAGE)).unwrap();
+
+        let verifier = Ed25519PhVerifier::from_public_key_with_context(
+            &signer.public_key().unwrap(),
+            Context::new(verifier_context).unwrap(),
+        )
+        .unwrap();
+
+        verifier.verify(Sha512::new().chain(PH_TEST_MESSAGE), &sig)
+    }
+
+    #[test]
+    fn signer_from_seed() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519Signer::from_seed(&seed).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), TEST_VECTORS[0].pk);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generates_distinct_signers() {
+        let mut csprng = rand_os::OsRng::new().unwrap();
+        let signer1 = Ed25519Signer::generate(&mut csprng);
+        let signer2 = Ed25519Signer::generate(&mut csprng);
+        assert_ne!(signer1.public_key().unwrap(), signer2.public_key().unwrap());
+    }
+
+    #[test]
+    fn signer_from_seed_checked_rejects_zero_seed() {
+        let seed = Ed25519Seed::new([0u8; SEED_LENGTH]);
+        let err = Ed25519Signer::from_seed_checked(&seed).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+
+        let mut bytes = [0u8; SEED_LENGTH];
+        bytes[..7].copy_from_slice(&[0xff; 7]);
+        let seed = Ed25519Seed::new(bytes);
+        assert!(Ed25519Signer::from_seed_checked(&seed).is_err());
+    }
+
+    #[test]
+    fn signer_from_seed_checked_accepts_random_seed() {
+        use rand_os::rand_core::RngCore;
+
+        let mut bytes = [0u8; SEED_LENGTH];
+        rand_os::OsRng::new().unwrap().fill_bytes(&mut bytes);
+        let seed = Ed25519Seed::new(bytes);
+        let signer = Ed25519Signer::from_seed_checked(&seed).unwrap();
+        assert_eq!(
+            signer.public_key().unwrap(),
+            Ed25519Signer::from_seed(&seed)
+                .unwrap()
+                .public_key()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn cloned_signer_signs_identically() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519Signer::from(&seed);
+        let cloned_signer = signer.clone();
+        assert_eq!(
+            ed25519::sign(&signer, PH_TEST_MESSAGE).unwrap(),
+            ed25519::sign(&cloned_signer, PH_TEST_MESSAGE).unwrap()
+        );
+    }
+
+    #[test]
+    fn cloned_ph_signer_signs_identically() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let context = Context::new(b"signatory-test").unwrap();
+        let signer = Ed25519PhSigner::new_with_context(&seed, context).unwrap();
+        let cloned_signer = signer.clone();
+        assert_eq!(
+            signer.sign(Sha512::new().chain(PH_TEST_MESSAGE)).unwrap(),
+            cloned_signer
+                .sign(Sha512::new().chain(PH_TEST_MESSAGE))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ph_rfc8032_test_vector() {
+        let seed = Ed25519Seed::from_bytes(RFC8032_PH_SEED).unwrap();
+        let signer = Ed25519PhSigner::from(&seed);
+        let public_key = signer.public_key().unwrap();
+        assert_eq!(public_key.as_ref(), &RFC8032_PH_PUBLIC_KEY);
+
+        let sig = signer
+            .sign(Sha512::new().chain(RFC8032_PH_MESSAGE))
+            .unwrap();
+        assert_eq!(sig.as_ref(), &RFC8032_PH_SIGNATURE[..]);
+
+        let verifier = Ed25519PhVerifier::from(&public_key);
+        assert!(verifier
+            .verify(Sha512::new().chain(RFC8032_PH_MESSAGE), &sig)
+            .is_ok());
+    }
+
+    #[test]
+    fn ph_rfc8032_tweaked_message_fails() {
+        let public_key = Ed25519PublicKey::new(RFC8032_PH_PUBLIC_KEY);
+        let sig = Ed25519Signature::from_bytes(&RFC8032_PH_SIGNATURE[..]).unwrap();
+
+        let mut tweaked_message = RFC8032_PH_MESSAGE.to_vec();
+        tweaked_message[0] ^= 0x01;
+
+        let verifier = Ed25519PhVerifier::from(&public_key);
+        let err = verifier
+            .verify(Sha512::new().chain(&tweaked_message), &sig)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn verifier_hex_round_trip() {
+        let hex = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        let verifier = Ed25519Verifier::from_hex(hex).unwrap();
+        assert_eq!(
+            verifier,
+            Ed25519Verifier::from(&Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap())
+        );
+        assert_eq!(verifier.to_string(), hex);
+        assert_eq!(hex.parse::<Ed25519Verifier>().unwrap(), verifier);
+    }
+
+    #[test]
+    fn verifier_from_malformed_hex() {
+        for hex in &[
+            "",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a00",
+            "D75A980182B10AB7D54BFED3C964073A0EE172F3DAA62325AF021A68F707511A",
+            "0xd75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "zz5a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        ] {
+            let err = Ed25519Verifier::from_hex(hex).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn verifier_from_hex_rejects_invalid_point() {
+        let hex = "0200000000000000000000000000000000000000000000000000000000000000";
+        let err = Ed25519Verifier::from_hex(hex).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn signer_matches_dalek_keypair_signatures() {
+        for vector in TEST_VECTORS {
+            let seed = Ed25519Seed::from_bytes(vector.sk).unwrap();
+            let signer = Ed25519Signer::from(&seed);
+            let keypair = keypair_from_seed(vector.sk).unwrap();
+
+            for msg in &[vector.msg, PH_TEST_MESSAGE] {
+                assert_eq!(
+                    signer.sign(msg).unwrap().as_ref(),
+                    &keypair.sign(msg).to_bytes()[..]
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn signer_sign_batch() {
+        use signatory::Verifier;
+
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519Signer::from(&seed);
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+
+        let messages: Vec<&[u8]> = TEST_VECTORS.iter().map(|vector| vector.msg).collect();
+        let signatures = signer.sign_batch(&messages).unwrap();
+        assert_eq!(signatures.len(), messages.len());
+
+        for (msg, sig) in messages.iter().zip(&signatures) {
+            assert!(verifier.verify(msg, sig).is_ok());
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "public key does not match seed")]
+    fn signer_with_mismatched_public_key() {
+        let keypair = Keypair {
+            secret: SecretKey::from_bytes(TEST_VECTORS[0].sk).unwrap(),
+            public: decode_public_key(TEST_VECTORS[1].pk).unwrap(),
+        };
+
+        Ed25519Signer::from_keypair(keypair);
+    }
+
+    #[test]
+    fn verifier_from_bytes() {
+        let mut pk = [0u8; 32];
+        pk.copy_from_slice(TEST_VECTORS[0].pk);
+
+        let expected = Ed25519Verifier::from(&Ed25519PublicKey::new(pk));
+        assert_eq!(Ed25519Verifier::from_bytes(&pk).unwrap(), expected);
+        assert_eq!(Ed25519Verifier::try_from(pk).unwrap(), expected);
+
+        let expected = Ed25519PhVerifier::from(&Ed25519PublicKey::new(pk));
+        assert_eq!(Ed25519PhVerifier::from_bytes(&pk).unwrap(), expected);
+        assert_eq!(Ed25519PhVerifier::try_from(pk).unwrap(), expected);
+    }
+
+    #[test]
+    fn verifier_as_ref() {
+        let public_key = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+
+        let verifier = Ed25519Verifier::from(&public_key);
+        assert_eq!(verifier.as_ref().len(), 32);
+        assert_eq!(verifier.as_ref(), public_key.as_ref());
+
+        let verifier = Ed25519PhVerifier::from(&public_key);
+        assert_eq!(verifier.as_ref().len(), 32);
+        assert_eq!(verifier.as_ref(), public_key.as_ref());
+    }
+
+    #[test]
+    fn verifier_as_dalek() {
+        let public_key = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let verifier = Ed25519Verifier::from(&public_key);
+        assert_eq!(verifier.as_dalek().as_bytes(), public_key.as_bytes());
+
+        let verifier = Ed25519PhVerifier::from(&public_key);
+        assert_eq!(verifier.as_dalek().as_bytes(), public_key.as_bytes());
+    }
+
+    #[test]
+    fn verifier_matches_bytes() {
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        assert!(verifier.matches_bytes(TEST_VECTORS[0].pk));
+        assert!(!verifier.matches_bytes(TEST_VECTORS[1].pk));
+
+        let mut flipped = [0u8; PUBLIC_KEY_LENGTH];
+        flipped.copy_from_slice(TEST_VECTORS[0].pk);
+        flipped[PUBLIC_KEY_LENGTH - 1] ^= 1;
+        assert!(!verifier.matches_bytes(&flipped));
+    }
+
+    #[test]
+    fn verifier_matches_bytes_rejects_wrong_length() {
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        assert!(!verifier.matches_bytes(&TEST_VECTORS[0].pk[..31]));
+        assert!(!verifier.matches_bytes(&[]));
+
+        let mut extended = TEST_VECTORS[0].pk.to_vec();
+        extended.push(0);
+        assert!(!verifier.matches_bytes(&extended));
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn verifier_ct_eq() {
+        let verifiers: Vec<_> = TEST_VECTORS
+            .iter()
+            .map(|vector| Ed25519Verifier::from_bytes(vector.pk).unwrap())
+            .collect();
+
+        for a in &verifiers {
+            for b in &verifiers {
+                assert_eq!(bool::from(a.ct_eq(b)), a == b);
+            }
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn signatures_ct_eq_matches_byte_comparison() {
+        use super::signatures_ct_eq;
+
+        let mut sigs: Vec<_> = TEST_VECTORS
+            .iter()
+            .map(|vector| Ed25519Signature::from_bytes(vector.sig).unwrap())
+            .collect();
+
+        // Differing only in the last byte
+        let mut bytes = *sigs[0].as_bytes();
+        bytes[63] ^= 1;
+        sigs.push(Ed25519Signature::new(bytes));
+
+        for a in &sigs {
+            for b in &sigs {
+                let expected = a.as_bytes()[..] == b.as_bytes()[..];
+                assert_eq!(bool::from(signatures_ct_eq(a, b)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn verifier_to_x25519_bytes() {
+        // Output of libsodium's `crypto_sign_ed25519_pk_to_curve25519` for
+        // the public key of the first RFC 8032 test vector
+        const X25519_PUBLIC_KEY: [u8; 32] = [
+            0xd8, 0x5e, 0x07, 0xec, 0x22, 0xb0, 0xad, 0x88, 0x15, 0x37, 0xc2, 0xf4, 0x4d, 0x66,
+            0x2d, 0x1a, 0x14, 0x3c, 0xf8, 0x30, 0xc5, 0x7a, 0xca, 0x43, 0x05, 0xd8, 0x5c, 0x7a,
+            0x90, 0xf6, 0xb6, 0x2e,
+        ];
+
+        let verifier = Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        assert_eq!(verifier.to_x25519_bytes(), X25519_PUBLIC_KEY);
+    }
+
+    #[test]
+    fn verify_rejects_malformed_signature() {
+        use signatory::Verifier;
+
+        let vector = &TEST_VECTORS[0];
+
+        // Signatures can't be constructed from truncated input in the first place
+        assert!(Ed25519Signature::from_bytes(&vector.sig[..10]).is_err());
+
+        // Dalek rejects signatures with the high bits of `s` set
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(vector.sig);
+        bytes[63] |= 0xe0;
+        let sig = Ed25519Signature::new(bytes);
+
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let err = Verifier::verify(&verifier, vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        let verifier = Ed25519PhVerifier::from_bytes(vector.pk).unwrap();
+        let err = verifier.verify_message(vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn verifiers_as_hash_set_keys() {
+        use std::collections::HashSet;
+
+        let mut verifiers = HashSet::new();
+        assert!(verifiers.insert(Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap()));
+        assert!(!verifiers.insert(Ed25519Verifier::from_bytes(TEST_VECTORS[0].pk).unwrap()));
+        assert!(verifiers.insert(Ed25519Verifier::from_bytes(TEST_VECTORS[1].pk).unwrap()));
+        assert_eq!(verifiers.len(), 2);
+
+        let context = Context::new(b"signatory-test").unwrap();
+        let public_key = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let mut ph_verifiers = HashSet::new();
+        assert!(ph_verifiers.insert(Ed25519PhVerifier::from_public_key(&public_key).unwrap()));
+        assert!(!ph_verifiers.insert(Ed25519PhVerifier::from_public_key(&public_key).unwrap()));
+        assert!(ph_verifiers.insert(
+            Ed25519PhVerifier::from_public_key_with_context(&public_key, context).unwrap()
+        ));
+        assert_eq!(ph_verifiers.len(), 2);
+    }
+
+    #[test]
+    fn verifiers_sort_by_public_key_bytes() {
+        let mut verifiers = TEST_VECTORS
+            .iter()
+            .map(|vector| Ed25519Verifier::from_bytes(vector.pk).unwrap())
+            .collect::<Vec<_>>();
+        verifiers.sort();
+
+        let mut public_keys = TEST_VECTORS
+            .iter()
+            .map(|vector| vector.pk)
+            .collect::<Vec<_>>();
+        public_keys.sort();
+
+        let sorted = verifiers.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
+        assert_eq!(sorted, public_keys);
+    }
+
+    #[test]
+    fn signature_into_byte_array_round_trip() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let sig = signer.sign(TEST_VECTORS[0].msg).unwrap();
+
+        let bytes: [u8; SIGNATURE_LENGTH] = sig.clone().into();
+        assert_eq!(&bytes[..], TEST_VECTORS[0].sig);
+        assert_eq!(Ed25519Signature::from_bytes(&bytes[..]).unwrap(), sig);
+    }
+
+    #[test]
+    fn verify_compat_accepts_flipped_key_signature() {
+        use curve25519_dalek::scalar::Scalar;
+        use signatory::Verifier;
+
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+
+        // Sign under the negated public key with the negated secret scalar
+        let expanded = expand_seed(vector.sk);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&expanded[..32]);
+        let mut negated = [0u8; 64];
+        negated[..32].copy_from_slice((-Scalar::from_bits(scalar)).as_bytes());
+        negated[32..].copy_from_slice(&expanded[32..]);
+
+        let mut flipped_pk = [0u8; 32];
+        flipped_pk.copy_from_slice(vector.pk);
+        flipped_pk[31] ^= 0x80;
+        let flipped_key = ed25519_dalek::PublicKey::from_bytes(&flipped_pk).unwrap();
+
+        let sig = Ed25519Signature::new(
+            ExpandedSecretKey::from_bytes(&negated)
+                .unwrap()
+                .sign(vector.msg, &flipped_key)
+                .to_bytes(),
+        );
+
+        assert!(verifier.verify(vector.msg, &sig).is_err());
+        assert!(verifier.verify_compat(vector.msg, &sig).is_ok());
+        assert!(verifier.verify_compat(b"other message", &sig).is_err());
+    }
+
+    #[test]
+    fn verify_many_stops_at_bad_item() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let verifier = signer.verifier();
+
+        let mut items: Vec<(&[u8], Ed25519Signature)> = TEST_VECTORS
+            .iter()
+            .map(|vector| (vector.msg, signer.sign(vector.msg).unwrap()))
+            .collect();
+        assert!(verifier.verify_many(&items).is_ok());
+        assert!(verifier.verify_many(&[]).is_ok());
+
+        items[1].0 = b"tampered";
+        let err = verifier.verify_many(&items).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn is_valid_reports_verification_result() {
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+        assert!(verifier.is_valid(vector.msg, &sig));
+        assert!(!verifier.is_valid(b"other message", &sig));
+
+        let mut tampered = [0u8; SIGNATURE_LENGTH];
+        tampered.copy_from_slice(vector.sig);
+        tampered[0] ^= 1;
+        let tampered = Ed25519Signature::new(tampered);
+        assert!(!verifier.is_valid(vector.msg, &tampered));
+
+        // Set the high bits of `s`, which ed25519-dalek rejects outright
+        let mut malformed = [0u8; SIGNATURE_LENGTH];
+        malformed.copy_from_slice(vector.sig);
+        malformed[SIGNATURE_LENGTH - 1] |= 0xe0;
+        let malformed = Ed25519Signature::new(malformed);
+        assert!(!verifier.is_valid(vector.msg, &malformed));
+    }
+
+    #[test]
+    fn verify_then_returns_message() {
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+
+        let msg = verifier.verify_then(vector.msg, &sig).unwrap();
+        assert_eq!(msg, vector.msg);
+
+        let err = verifier.verify_then(b"other message", &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn verify_compat_accepts_regular_signature() {
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+        assert!(verifier.verify_compat(vector.msg, &sig).is_ok());
+    }
+
+    #[test]
+    fn verify_byteswapped_s_accepts_swapped_signature() {
+        use signatory::Verifier;
+
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+
+        let mut swapped = [0u8; SIGNATURE_LENGTH];
+        swapped.copy_from_slice(vector.sig);
+        swapped[32..].reverse();
+        let swapped = Ed25519Signature::new(swapped);
+
+        assert!(verifier.verify(vector.msg, &swapped).is_err());
+        assert!(verifier.verify_byteswapped_s(vector.msg, &swapped).is_ok());
+        assert!(verifier.verify_byteswapped_s(vector.msg, &sig).is_err());
+        assert!(verifier
+            .verify_byteswapped_s(b"other message", &swapped)
+            .is_err());
+    }
+
+    #[test]
+    fn length_constants_match_dalek() {
+        assert_eq!(SEED_LENGTH, ed25519_dalek::SECRET_KEY_LENGTH);
+        assert_eq!(PUBLIC_KEY_LENGTH, ed25519_dalek::PUBLIC_KEY_LENGTH);
+        assert_eq!(SIGNATURE_LENGTH, ed25519_dalek::SIGNATURE_LENGTH);
+
+        assert_eq!(SEED_LENGTH, ed25519::SEED_SIZE);
+        assert_eq!(PUBLIC_KEY_LENGTH, ed25519::PUBLIC_KEY_SIZE);
+        assert_eq!(SIGNATURE_LENGTH, SIGNATURE_SIZE);
+
+        // These only compile if the constants match the sizes of dalek's types
+        let keypair = keypair_from_seed(TEST_VECTORS[0].sk).unwrap();
+        let _: [u8; SEED_LENGTH] = keypair.secret.to_bytes();
+        let _: [u8; PUBLIC_KEY_LENGTH] = keypair.public.to_bytes();
+        let _: [u8; SIGNATURE_LENGTH] = keypair.sign(TEST_VECTORS[0].msg).to_bytes();
+    }
+
+    #[test]
+    fn verify_bounded_accepts_message_within_limit() {
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+
+        assert!(verifier
+            .verify_bounded(vector.msg, &sig, vector.msg.len())
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_bounded_rejects_message_over_limit() {
+        let vector = &TEST_VECTORS[2];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = Ed25519Signature::from_bytes(vector.sig).unwrap();
+
+        let err = verifier
+            .verify_bounded(vector.msg, &sig, vector.msg.len() - 1)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn verifier_from_bytes_wrong_length() {
+        for len in &[0, 31, 33] {
+            let bytes = [0u8; 33];
+            let err = Ed25519Verifier::from_bytes(&bytes[..*len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+            let err = Ed25519PhVerifier::from_bytes(&bytes[..*len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn verifier_from_bytes_invalid_point() {
+        let err = Ed25519Verifier::from_bytes(&INVALID_POINT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        let err = Ed25519PhVerifier::from_bytes(&INVALID_POINT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn verifier_rejects_non_canonical_point() {
+        let err = Ed25519Verifier::try_from(NON_CANONICAL_POINT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        let err = Ed25519PhVerifier::try_from(NON_CANONICAL_POINT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn verifier_from_public_key_accepts_non_canonical_point() {
+        let public_key = Ed25519PublicKey::new(NON_CANONICAL_POINT);
+
+        assert!(Ed25519Verifier::from_public_key(&public_key).is_ok());
+        assert!(Ed25519PhVerifier::from_public_key(&public_key).is_ok());
+        assert!(Ed25519StrictVerifier::from_public_key(&public_key).is_ok());
+
+        // The deprecated infallible impls must not panic on these either
+        let _ = Ed25519Verifier::from(&public_key);
+        let _ = Ed25519PhVerifier::from(&public_key);
+        let _ = Ed25519StrictVerifier::from(&public_key);
+    }
+
+    #[test]
+    fn verifier_from_edwards_compressed() {
+        for vector in TEST_VECTORS {
+            let mut bytes = [0u8; PUBLIC_KEY_LENGTH];
+            bytes.copy_from_slice(vector.pk);
+
+            let verifier = Ed25519Verifier::from_edwards_compressed(&bytes).unwrap();
+            assert_eq!(verifier.as_ref(), vector.pk);
+        }
+    }
+
+    #[test]
+    fn verifier_from_edwards_compressed_rejects_ristretto() {
+        use curve25519_dalek::{
+            constants::RISTRETTO_BASEPOINT_POINT, edwards::CompressedEdwardsY, scalar::Scalar,
+        };
+
+        // Find a Ristretto encoding which is not also a valid Edwards point
+        let bytes = (1u64..256)
+            .map(|i| {
+                (RISTRETTO_BASEPOINT_POINT * Scalar::from(i))
+                    .compress()
+                    .to_bytes()
+            })
+            .find(|bytes| CompressedEdwardsY(*bytes).decompress().is_none())
+            .unwrap();
+
+        let err = Ed25519Verifier::from_edwards_compressed(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+
+        // Error descriptions are only retained with `std`
+        #[cfg(feature = "std")]
+        assert!(err.to_string().contains("Ristretto"));
+    }
+
+    #[test]
+    fn verifier_from_bytes_canonical_rejects_unreduced_y() {
+        for vector in TEST_VECTORS {
+            assert!(Ed25519Verifier::from_bytes_canonical(vector.pk).is_ok());
+        }
+
+        // `y = p - 1` is the largest canonical `y` coordinate
+        let mut point = NON_CANONICAL_POINT;
+        point[0] = 0xec;
+        assert!(Ed25519Verifier::from_bytes_canonical(&point).is_ok());
+
+        // `y = p`, and `y = p + 1` with and without the sign bit of `x` set:
+        // Dalek (and so `from_public_key`) accepts all of these
+        for &(low, high) in &[(0xed, 0x7f), (0xee, 0x7f), (0xee, 0xff)] {
+            let mut point = NON_CANONICAL_POINT;
+            point[0] = low;
+            point[31] = high;
+
+            let public_key = Ed25519PublicKey::new(point);
+            assert!(Ed25519Verifier::from_public_key(&public_key).is_ok());
+
+            let err = Ed25519Verifier::from_bytes_canonical(&point).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+            let err = Ed25519Verifier::from_bytes(&point).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        }
+
+        let err = Ed25519Verifier::from_bytes_canonical(&NON_CANONICAL_POINT[..31]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn verifier_from_public_key_checked() {
+        let public_key = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        assert!(Ed25519Verifier::from_public_key_checked(&public_key).is_ok());
+        assert!(Ed25519PhVerifier::from_public_key_checked(&public_key).is_ok());
+
+        for point in &SMALL_ORDER_POINTS {
+            let public_key = Ed25519PublicKey::new(*point);
+
+            // Small-order points are otherwise valid public keys
+            assert!(Ed25519Verifier::from_public_key(&public_key).is_ok());
+
+            let err = Ed25519Verifier::from_public_key_checked(&public_key).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+            let err = Ed25519PhVerifier::from_public_key_checked(&public_key).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        }
+    }
+
+    #[test]
+    fn ph_signer_sign_message() {
+        let seed = Ed25519Seed::from_bytes(RFC8032_PH_SEED).unwrap();
+        let signer = Ed25519PhSigner::from(&seed);
+        let sig = signer.sign_message(RFC8032_PH_MESSAGE).unwrap();
+        assert_eq!(sig.as_ref(), &RFC8032_PH_SIGNATURE[..]);
+
+        let verifier = Ed25519PhVerifier::from(&signer.public_key().unwrap());
+        assert!(verifier
+            .verify(Sha512::new().chain(RFC8032_PH_MESSAGE), &sig)
+            .is_ok());
+    }
+
+    #[test]
+    fn ph_sign_message_verify_message_round_trip() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519PhSigner::from(&seed);
+        let verifier = Ed25519PhVerifier::from(&signer.public_key().unwrap());
+
+        let sig = signer.sign_message(PH_TEST_MESSAGE).unwrap();
+        assert!(verifier.verify_message(PH_TEST_MESSAGE, &sig).is_ok());
+
+        let mut tweaked_message = PH_TEST_MESSAGE.to_vec();
+        tweaked_message[0] ^= 0x01;
+
+        let err = verifier.verify_message(&tweaked_message, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn signer_debug_redacts_secret() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let seed_hex: String = TEST_VECTORS[0]
+            .sk
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let pk_hex: String = TEST_VECTORS[0]
+            .pk
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+
+        for debug in &[
+            format!("{:?}", Ed25519Signer::from(&seed)),
+            format!("{:?}", Ed25519PhSigner::from(&seed)),
+        ] {
+            assert!(debug.contains(&pk_hex));
+            assert!(debug.contains("[REDACTED]"));
+            assert!(!debug.contains(&seed_hex));
+        }
+    }
+
+    #[test]
+    fn signer_from_keypair_bytes() {
+        let vector = &TEST_VECTORS[0];
+        let mut keypair = [0u8; 64];
+        keypair[..32].copy_from_slice(vector.sk);
+        keypair[32..].copy_from_slice(vector.pk);
+
+        let signer = Ed25519Signer::from_keypair_bytes(&keypair).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), vector.pk);
+        assert_eq!(
+            ed25519::sign(&signer, vector.msg).unwrap().as_ref(),
+            vector.sig
+        );
+    }
+
+    #[test]
+    fn keypair_bytes_round_trip() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let keypair = Ed25519Signer::from(&seed).to_keypair_bytes().unwrap();
+        assert_eq!(&keypair[..32], TEST_VECTORS[0].sk);
+        assert_eq!(&keypair[32..], TEST_VECTORS[0].pk);
+
+        let signer = Ed25519Signer::from_keypair_bytes(&keypair).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), TEST_VECTORS[0].pk);
+    }
+
+    /// Expand a seed into a clamped secret scalar and nonce prefix as in
+    /// RFC 8032 Section 5.1.5
+    fn expand_seed(seed: &[u8]) -> [u8; 64] {
+        let mut expanded = [0u8; 64];
+        expanded.copy_from_slice(&Sha512::digest(seed));
+        expanded[0] &= 0b1111_1000;
+        expanded[31] &= 0b0111_1111;
+        expanded[31] |= 0b0100_0000;
+        expanded
+    }
+
+    #[test]
+    fn signer_from_expanded_secret() {
+        use signatory::Verifier;
+
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::from_expanded_secret(&expand_seed(vector.sk)).unwrap();
+            assert_eq!(signer.public_key().unwrap().as_bytes(), vector.pk);
+
+            let sig = signer.clone().sign(vector.msg).unwrap();
+            assert_eq!(sig.as_ref(), vector.sig);
+
+            let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+            assert!(verifier.verify(vector.msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn expanded_secret_signer_has_no_seed() {
+        let signer = Ed25519Signer::from_expanded_secret(&expand_seed(TEST_VECTORS[0].sk)).unwrap();
+        assert_eq!(
+            signer.to_seed().err().unwrap().kind(),
+            ErrorKind::KeyInvalid
+        );
+        assert_eq!(
+            signer.serialize().err().unwrap().kind(),
+            ErrorKind::KeyInvalid
+        );
+        assert_eq!(
+            signer.to_keypair_bytes().err().unwrap().kind(),
+            ErrorKind::KeyInvalid
+        );
+    }
+
+    #[test]
+    fn signer_from_unclamped_expanded_secret() {
+        let expanded = expand_seed(TEST_VECTORS[0].sk);
+
+        for &(index, bit) in &[(0, 0b0000_0001), (31, 0b1000_0000)] {
+            let mut unclamped = expanded;
+            unclamped[index] |= bit;
+            let result = Ed25519Signer::from_expanded_secret(&unclamped);
+            assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+        }
+
+        let mut unclamped = expanded;
+        unclamped[31] &= 0b1011_1111;
+        let result = Ed25519Signer::from_expanded_secret(&unclamped);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519Signer::from(&seed);
+        assert_eq!(
+            signer.to_seed().unwrap().as_secret_slice(),
+            TEST_VECTORS[0].sk
+        );
+    }
+
+    #[test]
+    fn rejects_keypair_bytes_with_mismatched_public_key() {
+        let mut keypair = [0u8; 64];
+        keypair[..32].copy_from_slice(TEST_VECTORS[0].sk);
+        keypair[32..].copy_from_slice(TEST_VECTORS[1].pk);
+
+        let result = Ed25519Signer::from_keypair_bytes(&keypair);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn rejects_malformed_keypair_bytes() {
+        let result = Ed25519Signer::from_keypair_bytes(TEST_VECTORS[0].sk);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn rejects_malformed_seed() {
+        let result = keypair_from_seed(&TEST_VECTORS[0].sk[..31]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn verifier_from_public_key() {
+        let pk = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        assert!(Ed25519Verifier::from_public_key(&pk).is_ok());
+        assert!(Ed25519PhVerifier::from_public_key(&pk).is_ok());
+    }
+
+    #[test]
+    fn verifier_rejects_invalid_point() {
+        let pk = Ed25519PublicKey::new(INVALID_POINT);
+        let result = Ed25519Verifier::from_public_key(&pk);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn ph_verifier_rejects_invalid_point() {
+        let pk = Ed25519PublicKey::new(INVALID_POINT);
+        let result = Ed25519PhVerifier::from_public_key(&pk);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn fingerprint_identifies_public_key() {
+        let pk0 = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let pk1 = Ed25519PublicKey::from_bytes(TEST_VECTORS[1].pk).unwrap();
+        let verifier = Ed25519Verifier::from_public_key(&pk0).unwrap();
+
+        assert_eq!(
+            verifier.fingerprint(),
+            Ed25519Verifier::from_public_key(&pk0)
+                .unwrap()
+                .fingerprint()
+        );
+        assert_ne!(
+            verifier.fingerprint(),
+            Ed25519Verifier::from_public_key(&pk1)
+                .unwrap()
+                .fingerprint()
+        );
+        assert_eq!(
+            &verifier.fingerprint()[..],
+            &Sha256::digest(TEST_VECTORS[0].pk)[..]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fingerprint_hex_encodes_fingerprint() {
+        let pk = Ed25519PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap();
+        let verifier = Ed25519Verifier::from_public_key(&pk).unwrap();
+        let expected: String = verifier
+            .fingerprint()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        assert_eq!(verifier.fingerprint_hex(), expected);
+    }
+
+    #[test]
+    fn signer_from_seed_and_matching_public_key() {
+        let vector = &TEST_VECTORS[0];
+        let seed = Ed25519Seed::from_bytes(vector.sk).unwrap();
+        let public_key = Ed25519PublicKey::from_bytes(vector.pk).unwrap();
+
+        let signer = Ed25519Signer::from_seed_and_public_key(&seed, &public_key).unwrap();
+        assert_eq!(signer.public_key().unwrap(), public_key);
+    }
+
+    #[test]
+    fn signer_from_seed_and_mismatched_public_key() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let public_key = Ed25519PublicKey::from_bytes(TEST_VECTORS[1].pk).unwrap();
+
+        let result = Ed25519Signer::from_seed_and_public_key(&seed, &public_key);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn signer_serialize_round_trip() {
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let bytes = signer.serialize().unwrap();
+            assert_eq!(&bytes[..], vector.sk);
+
+            let deserialized = Ed25519Signer::deserialize(&bytes).unwrap();
+            assert_eq!(deserialized.public_key().unwrap().as_bytes(), vector.pk);
+            assert_eq!(
+                deserialized.sign(vector.msg).unwrap(),
+                signer.sign(vector.msg).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn verify_public_key_accepts_matching_key() {
+        let vector = &TEST_VECTORS[0];
+        let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+        let expected = Ed25519PublicKey::from_bytes(vector.pk).unwrap();
+        assert!(signer.verify_public_key(&expected).is_ok());
+    }
+
+    #[test]
+    fn verify_public_key_rejects_mismatched_key() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let expected = Ed25519PublicKey::from_bytes(TEST_VECTORS[1].pk).unwrap();
+        let err = signer.verify_public_key(&expected).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn sign_with_public_key_verifies() {
+        use signatory::Verifier;
+
+        let vector = &TEST_VECTORS[0];
+        let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+        let (public_key, signature) = signer.sign_with_public_key(vector.msg).unwrap();
+
+        assert_eq!(public_key.as_bytes(), vector.pk);
+        let verifier = Ed25519Verifier::from(&public_key);
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn signer_verifier_verifies_signatures() {
+        use signatory::Verifier;
+
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let verifier = signer.verifier();
+
+            assert_eq!(verifier, Ed25519Verifier::from_bytes(vector.pk).unwrap());
+            let sig = signer.sign(vector.msg).unwrap();
+            assert!(verifier.verify(vector.msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn signer_try_from_seed_slice() {
+        let vector = &TEST_VECTORS[0];
+        let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_bytes(), vector.pk);
+    }
+
+    #[test]
+    fn signer_try_from_rejects_short_slice() {
+        let result = Ed25519Signer::try_from(&TEST_VECTORS[0].sk[..31]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn signer_try_from_rejects_keypair_sized_slice() {
+        let result = Ed25519Signer::try_from(&[0u8; 64][..]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn ph_signer_try_from_seed_slice() {
+        let vector = &TEST_VECTORS[0];
+        let signer = Ed25519PhSigner::try_from(vector.sk).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_bytes(), vector.pk);
+    }
+
+    #[test]
+    fn ph_signer_try_from_rejects_short_slice() {
+        let result = Ed25519PhSigner::try_from(&TEST_VECTORS[0].sk[..31]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn ph_signer_try_from_rejects_keypair_sized_slice() {
+        let result = Ed25519PhSigner::try_from(&[0u8; 64][..]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn signer_zeroize_secret_disables_signing() {
+        let mut signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        assert!(signer.sign(TEST_VECTORS[0].msg).is_ok());
+
+        signer.zeroize_secret();
+        assert_eq!(signer.expanded.to_bytes(), [0u8; 64]);
+        assert!(signer.to_seed().is_err());
+
+        let err = signer.sign(TEST_VECTORS[0].msg).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+
+        let err = signer.clone().sign(TEST_VECTORS[0].msg).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+    }
+
+    #[test]
+    fn signer_to_prehash_signer_shares_key() {
+        let vector = &TEST_VECTORS[0];
+        let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+        let ph_signer = signer.to_prehash_signer().unwrap();
+        assert_eq!(
+            ph_signer.public_key().unwrap(),
+            signer.public_key().unwrap()
+        );
+
+        let verifier = Ed25519PhVerifier::from_bytes(vector.pk).unwrap();
+        let sig = ph_signer.sign_message(PH_TEST_MESSAGE).unwrap();
+        assert!(verifier.verify_message(PH_TEST_MESSAGE, &sig).is_ok());
+    }
+
+    #[test]
+    fn ph_signer_to_signer_shares_key() {
+        let vector = &TEST_VECTORS[0];
+        let ph_signer = Ed25519PhSigner::try_from(vector.sk).unwrap();
+        let signer = ph_signer.to_signer();
+        assert_eq!(
+            signer.public_key().unwrap(),
+            ph_signer.public_key().unwrap()
+        );
+        assert_eq!(signer.sign(vector.msg).unwrap().as_bytes(), vector.sig);
+    }
+
+    #[test]
+    fn signer_to_prehash_signer_requires_seed() {
+        let signer = Ed25519Signer::from_expanded_secret(&expand_seed(TEST_VECTORS[0].sk)).unwrap();
+        let err = signer.to_prehash_signer().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn ph_sign_with_per_call_context() {
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let public_key = signer.public_key().unwrap();
+        let digest = Sha512::new().chain(PH_TEST_MESSAGE);
+
+        // Contexts built at runtime, rather than `'static` ones
+        let context_a = format!("context {}", 'A');
+        let context_b = format!("context {}", 'B');
+
+        let sig_a = signer
+            .sign_with_context(digest.clone(), context_a.as_bytes())
+            .unwrap();
+        let sig_b = signer
+            .sign_with_context(digest.clone(), context_b.as_bytes())
+            .unwrap();
+        assert_ne!(sig_a, sig_b);
+
+        let verifier_a = Ed25519PhVerifier::from_public_key_with_context(
+            &public_key,
+            Context::new(b"context A").unwrap(),
+        )
+        .unwrap();
+        let verifier_b = Ed25519PhVerifier::from_public_key_with_context(
+            &public_key,
+            Context::new(b"context B").unwrap(),
+        )
+        .unwrap();
+
+        assert!(verifier_a.verify(digest.clone(), &sig_a).is_ok());
+        assert!(verifier_b.verify(digest.clone(), &sig_b).is_ok());
+        assert!(verifier_a.verify(digest.clone(), &sig_b).is_err());
+        assert!(verifier_b.verify(digest, &sig_a).is_err());
+    }
+
+    #[test]
+    fn ph_sign_with_context_matches_dalek() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let digest = Sha512::new().chain(PH_TEST_MESSAGE);
+
+        // Compare against ed25519-dalek's own Ed25519ph implementation
+        for &context in &[&b""[..], b"context A", &[0x42; MAX_CONTEXT_SIZE]] {
+            let expected = Ed25519PhSigner::new_with_context(&seed, Context::new(context).unwrap())
+                .unwrap()
+                .sign(digest.clone())
+                .unwrap();
+
+            let sig = signer.sign_with_context(digest.clone(), context).unwrap();
+            assert_eq!(sig, expected);
+        }
+    }
+
+    #[test]
+    fn ph_sign_with_context_rejects_oversized_context() {
+        const CONTEXT: &[u8] = &[0x42; MAX_CONTEXT_SIZE + 1];
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let err = signer
+            .sign_with_context(Sha512::new().chain(PH_TEST_MESSAGE), CONTEXT)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ContextInvalid);
+    }
+
+    #[test]
+    fn ph_sign_and_verify_shared_prehash() {
+        let seed = Ed25519Seed::from_bytes(TEST_VECTORS[0].sk).unwrap();
+        let context = Context::new(b"signatory-test").unwrap();
+        let signer = Ed25519PhSigner::new_with_context(&seed, context).unwrap();
+        let verifier =
+            Ed25519PhVerifier::from_public_key_with_context(&signer.public_key().unwrap(), context)
+                .unwrap();
+
+        let prehash = Sha512::digest(PH_TEST_MESSAGE);
+        let sig = signer.sign_prehash(&prehash).unwrap();
+
+        assert!(verifier.verify_prehash(&prehash, &sig).is_ok());
+        assert_eq!(sig, signer.sign_message(PH_TEST_MESSAGE).unwrap());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn shared_signer_implements_signer() {
+        use std::sync::Arc;
+
+        fn sign_with<S: Signer<Ed25519Signature>>(signer: S, msg: &[u8]) -> Ed25519Signature {
+            signer.sign(msg).unwrap()
+        }
+
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let expected = signer.sign(PH_TEST_MESSAGE).unwrap();
+
+        assert_eq!(sign_with(&signer, PH_TEST_MESSAGE), expected);
+        assert_eq!(sign_with(Arc::new(signer), PH_TEST_MESSAGE), expected);
+    }
+
+    /// SHA-512 behind a wrapper, standing in for an alternative (e.g.
+    /// hardware-accelerated) SHA-512 implementation
+    #[derive(Clone, Default)]
+    struct WrappedSha512(Sha512);
+
+    impl digest::Input for WrappedSha512 {
+        fn input<B: AsRef<[u8]>>(&mut self, data: B) {
+            Digest::input(&mut self.0, data)
+        }
+    }
+
+    impl digest::FixedOutput for WrappedSha512 {
+        type OutputSize = U64;
+
+        fn fixed_result(self) -> GenericArray<u8, U64> {
+            self.0.result()
+        }
+    }
+
+    impl digest::Reset for WrappedSha512 {
+        fn reset(&mut self) {
+            Digest::reset(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn ph_sign_and_verify_with_pluggable_digest() {
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let verifier = Ed25519PhVerifier::from_public_key(&signer.public_key().unwrap()).unwrap();
+        let (header, body) = PH_TEST_MESSAGE.split_at(7);
+
+        let sig = signer
+            .sign_message_with::<WrappedSha512>(PH_TEST_MESSAGE)
+            .unwrap();
+        assert_eq!(sig, signer.sign_message(PH_TEST_MESSAGE).unwrap());
+        assert_eq!(
+            sig,
+            signer
+                .sign_segments_with::<WrappedSha512>(&[header, body])
+                .unwrap()
+        );
+        assert!(verifier
+            .verify_message_with::<WrappedSha512>(PH_TEST_MESSAGE, &sig)
+            .is_ok());
+    }
+
+    #[test]
+    fn ph_sign_segments_matches_concatenation() {
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let (header, body) = PH_TEST_MESSAGE.split_at(7);
+
+        assert_eq!(
+            signer.sign_segments(&[header, body]).unwrap(),
+            signer.sign_message(&[header, body].concat()).unwrap()
+        );
+        assert_eq!(
+            signer.sign_segments(&[]).unwrap(),
+            signer.sign_message(b"").unwrap()
+        );
+    }
+
+    #[test]
+    fn ph_sign_prehash_rejects_wrong_length() {
+        let signer = Ed25519PhSigner::try_from(TEST_VECTORS[0].sk).unwrap();
+        let prehash = Sha512::digest(PH_TEST_MESSAGE);
+        let err = signer.sign_prehash(&prehash[..32]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn ph_context_shared_across_signers_and_verifiers() {
+        let context = Context::new(b"signatory-test").unwrap();
+
+        for vector in TEST_VECTORS {
+            let seed = Ed25519Seed::from_bytes(vector.sk).unwrap();
+            let signer = Ed25519PhSigner::new_with_context(&seed, context).unwrap();
+            let sig = signer.sign_message(PH_TEST_MESSAGE).unwrap();
+
+            let verifier = Ed25519PhVerifier::from_public_key_with_context(
+                &signer.public_key().unwrap(),
+                context,
+            )
+            .unwrap();
+            assert!(verifier.verify_message(PH_TEST_MESSAGE, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn ph_round_trip_with_empty_context() {
+        assert!(ph_context_round_trip(b"", b"").is_ok());
+    }
+
+    #[test]
+    fn ph_round_trip_with_short_context() {
+        assert!(ph_context_round_trip(b"signatory-test", b"signatory-test").is_ok());
+    }
+
+    #[test]
+    fn ph_round_trip_with_max_size_context() {
+        const CONTEXT: &[u8] = &[0x42; MAX_CONTEXT_SIZE];
+        assert!(ph_context_round_trip(CONTEXT, CONTEXT).is_ok());
+    }
+
+    #[test]
+    fn ph_verifier_rejects_mismatched_context() {
+        let result = ph_context_round_trip(b"context A", b"context B");
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::SignatureInvalid);
+    }
 }
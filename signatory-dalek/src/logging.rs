@@ -0,0 +1,120 @@
+//! Debug-level tracing of signing operations via the `log` crate
+
+use crate::Fingerprint;
+
+/// Emit a debug-level record for an Ed25519 signing operation over a message
+/// of the given length.
+///
+/// The key is identified by its public key fingerprint (see
+/// `Ed25519Verifier::fingerprint`): neither secret key material nor message
+/// contents are ever logged.
+pub(crate) fn log_sign(public_key: &ed25519_dalek::PublicKey, msg_len: usize) {
+    if log_enabled!(log::Level::Debug) {
+        debug!(
+            "Ed25519 sign: key={} msg_len={}",
+            Fingerprint::new(public_key),
+            msg_len
+        );
+    }
+}
+
+/// Emit a debug-level record for an Ed25519ph signing operation, whose
+/// message length is unknown as only its digest is given
+pub(crate) fn log_sign_prehashed(public_key: &ed25519_dalek::PublicKey) {
+    if log_enabled!(log::Level::Debug) {
+        debug!("Ed25519ph sign: key={}", Fingerprint::new(public_key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519PhSigner, Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use log::{Log, Metadata, Record};
+    use signatory::{PublicKeyed, Signer};
+    use std::{
+        string::{String, ToString},
+        sync::{Mutex, Once},
+        vec::Vec,
+    };
+
+    const MESSAGE: &[u8] = b"message which must not be logged";
+
+    /// Logger which records every message it receives
+    struct TestLogger;
+
+    static LOGGER: TestLogger = TestLogger;
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    static INIT: Once = Once::new();
+
+    impl Log for TestLogger {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            RECORDS.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn init_logger() {
+        INIT.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(log::LevelFilter::Debug);
+        });
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Find the records (possibly emitted by concurrently running tests)
+    /// which mention the given signer's key
+    fn records_for(fingerprint: &str) -> Vec<String> {
+        RECORDS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| record.contains(fingerprint))
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn sign_emits_record_without_secrets() {
+        init_logger();
+
+        // Use a key no other test signs with, as tests run concurrently
+        let seed = [0x2a; 32];
+        let signer = Ed25519Signer::try_from(&seed[..]).unwrap();
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+        let fingerprint = to_hex(&verifier.fingerprint());
+        signer.sign(MESSAGE).unwrap();
+
+        let records = records_for(&fingerprint);
+        assert_eq!(records.len(), 1);
+
+        let record = &records[0];
+        assert!(record.contains(&format!("msg_len={}", MESSAGE.len())));
+        assert!(!record.contains(&to_hex(&seed)));
+        assert!(!record.contains(&to_hex(MESSAGE)));
+    }
+
+    #[test]
+    fn ph_sign_emits_record_without_secrets() {
+        init_logger();
+
+        let seed = [0x2b; 32];
+        let signer = Ed25519PhSigner::try_from(&seed[..]).unwrap();
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+        let fingerprint = to_hex(&verifier.fingerprint());
+        signer.sign_message(MESSAGE).unwrap();
+
+        let records = records_for(&fingerprint);
+        assert_eq!(records.len(), 1);
+        assert!(records[0].starts_with("Ed25519ph sign:"));
+        assert!(!records[0].contains(&to_hex(&seed)));
+    }
+}
@@ -0,0 +1,115 @@
+//! Conversion between Ed25519 seeds and BIP39 mnemonic phrases
+//!
+//! The 32-byte seed is used directly as BIP39 entropy, yielding a 24-word
+//! English phrase whose last word includes an 8-bit checksum. This is a
+//! backup encoding of the seed itself: it is *not* compatible with wallets
+//! which derive keys from the PBKDF2 "BIP39 seed" of a phrase (e.g. SLIP-0010),
+//! and the same phrase will yield different keys there.
+
+use alloc::string::{String, ToString};
+use bip39::{Language, Mnemonic};
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+
+use crate::{clear_bytes, Ed25519Signer};
+
+/// Number of words in the mnemonic phrase for a 32-byte seed
+pub const MNEMONIC_WORDS: usize = 24;
+
+impl Ed25519Signer {
+    /// Create a new Ed25519 signer from a 24-word BIP39 mnemonic phrase, as
+    /// produced by `seed_to_mnemonic`.
+    ///
+    /// Returns `ParseError` if the phrase contains unknown words, has the
+    /// wrong number of words, or fails the checksum.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self, Error> {
+        let mnemonic = Mnemonic::parse_in(Language::English, phrase)
+            .map_err(|_| Error::new(ErrorKind::ParseError, Some("invalid mnemonic phrase")))?;
+
+        if mnemonic.word_count() != MNEMONIC_WORDS {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("expected a 24-word mnemonic phrase"),
+            ));
+        }
+
+        let (mut entropy, len) = mnemonic.to_entropy_array();
+        debug_assert_eq!(len, ed25519::SEED_SIZE);
+
+        let result = ed25519::Seed::from_bytes(&entropy[..ed25519::SEED_SIZE])
+            .and_then(|seed| Self::from_seed(&seed));
+
+        clear_bytes(&mut entropy);
+        result
+    }
+}
+
+/// Encode an Ed25519 seed as a 24-word BIP39 mnemonic phrase (English
+/// wordlist, words separated by single spaces).
+///
+/// The returned phrase encodes the seed itself: the caller is responsible for
+/// zeroizing it once it is no longer needed.
+pub fn seed_to_mnemonic(seed: &ed25519::Seed) -> String {
+    Mnemonic::from_entropy_in(Language::English, seed.as_secret_slice())
+        .expect("32 bytes is a valid BIP39 entropy length")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{seed_to_mnemonic, MNEMONIC_WORDS};
+    use crate::Ed25519Signer;
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed,
+    };
+
+    /// BIP39 English test vector for 32 bytes of `0x7f`
+    const TEST_PHRASE: &str = "legal winner thank year wave sausage worth useful legal winner \
+                               thank year wave sausage worth useful legal winner thank year \
+                               wave sausage worth title";
+
+    #[test]
+    fn mnemonic_round_trip() {
+        for vector in TEST_VECTORS {
+            let seed = Seed::from_bytes(vector.sk).unwrap();
+            let phrase = seed_to_mnemonic(&seed);
+            assert_eq!(phrase.split(' ').count(), MNEMONIC_WORDS);
+
+            let signer = Ed25519Signer::from_mnemonic(&phrase).unwrap();
+            assert_eq!(signer.public_key().unwrap().as_ref(), vector.pk);
+        }
+    }
+
+    #[test]
+    fn mnemonic_matches_bip39_vector() {
+        let seed = Seed::new([0x7f; 32]);
+        assert_eq!(seed_to_mnemonic(&seed), TEST_PHRASE);
+
+        let signer = Ed25519Signer::from_mnemonic(TEST_PHRASE).unwrap();
+        assert_eq!(signer.to_seed().unwrap().as_secret_slice(), &[0x7f; 32]);
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        // Replace the last word (which carries the checksum) with another
+        // valid word
+        let phrase = TEST_PHRASE.replace("title", "legal");
+        let err = Ed25519Signer::from_mnemonic(&phrase).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn rejects_unknown_words_and_short_phrases() {
+        let phrase = TEST_PHRASE.replace("winner", "signatory");
+        assert!(Ed25519Signer::from_mnemonic(&phrase).is_err());
+
+        // Valid 12-word phrase (16 bytes of entropy)
+        let short = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let err = Ed25519Signer::from_mnemonic(short).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+}
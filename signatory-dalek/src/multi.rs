@@ -0,0 +1,89 @@
+//! Verification against a set of trusted Ed25519 public keys
+
+use alloc::vec::Vec;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Verifier,
+};
+
+use crate::Ed25519Verifier;
+
+/// Ed25519 verifier which accepts signatures made by any one of a set of
+/// trusted public keys, e.g. keys overlapping during a key rotation, or each
+/// of several authorized signers
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MultiVerifier {
+    verifiers: Vec<Ed25519Verifier>,
+}
+
+impl MultiVerifier {
+    /// Create a new multi-key verifier which trusts the given verifiers
+    pub fn new(verifiers: Vec<Ed25519Verifier>) -> Self {
+        MultiVerifier { verifiers }
+    }
+
+    /// Borrow the verifiers trusted by this multi-key verifier
+    pub fn verifiers(&self) -> &[Ed25519Verifier] {
+        &self.verifiers
+    }
+
+    /// Verify the given signature against each trusted key in order,
+    /// returning the index of the first one it is valid for, or
+    /// `SignatureInvalid` if it is valid for none of them
+    pub fn verify_any(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<usize, Error> {
+        self.verifiers
+            .iter()
+            .position(|verifier| verifier.verify(msg, sig).is_ok())
+            .ok_or_else(|| ErrorKind::SignatureInvalid.into())
+    }
+}
+
+impl From<Vec<Ed25519Verifier>> for MultiVerifier {
+    fn from(verifiers: Vec<Ed25519Verifier>) -> Self {
+        Self::new(verifiers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiVerifier;
+    use crate::Ed25519Verifier;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        Signature,
+    };
+
+    fn multi_verifier() -> MultiVerifier {
+        TEST_VECTORS[..3]
+            .iter()
+            .map(|vector| Ed25519Verifier::from_bytes(vector.pk).unwrap())
+            .collect::<std::vec::Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn matches_second_key() {
+        let vector = &TEST_VECTORS[1];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+        assert_eq!(multi_verifier().verify_any(vector.msg, &sig).unwrap(), 1);
+    }
+
+    #[test]
+    fn matches_no_key() {
+        let vector = &TEST_VECTORS[3];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+        let err = multi_verifier().verify_any(vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn empty_set_matches_nothing() {
+        let vector = &TEST_VECTORS[0];
+        let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+        assert!(MultiVerifier::default()
+            .verify_any(vector.msg, &sig)
+            .is_err());
+    }
+}
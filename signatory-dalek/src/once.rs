@@ -0,0 +1,120 @@
+//! Enforcement of one-time signing for protocols which forbid signing the
+//! same message twice
+
+use alloc::collections::BTreeSet;
+use digest::Digest;
+use sha2::Sha256;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    PublicKeyed, Signer,
+};
+
+/// Wrapper around an Ed25519 signer which refuses to sign any message more
+/// than once, returning `ProviderError` on a repeat.
+///
+/// The SHA-256 digest of every message signed successfully is remembered for
+/// the lifetime of the wrapper, so memory use grows by 32 bytes per message.
+/// Messages are only tracked in memory: the guard does not survive restarts,
+/// and two wrappers around the same key do not know about each other.
+///
+/// Signing requires `&mut self` to record the message, so this type does not
+/// implement the `Signer` trait (which signs through `&self`).
+#[derive(Debug)]
+pub struct OnceSigner<S>
+where
+    S: Signer<ed25519::Signature>,
+{
+    /// Signer which produces the signatures
+    signer: S,
+
+    /// SHA-256 digests of the messages signed so far
+    signed: BTreeSet<[u8; 32]>,
+}
+
+impl<S> OnceSigner<S>
+where
+    S: Signer<ed25519::Signature>,
+{
+    /// Wrap the given signer, which has not signed any messages yet
+    pub fn new(signer: S) -> Self {
+        OnceSigner {
+            signer,
+            signed: BTreeSet::new(),
+        }
+    }
+
+    /// Sign the given message, returning `ProviderError` if this wrapper has
+    /// already signed it.
+    ///
+    /// The message is only recorded if signing succeeds, so a message whose
+    /// signing failed can be retried.
+    pub fn sign(&mut self, msg: &[u8]) -> Result<ed25519::Signature, Error> {
+        let digest = message_digest(msg);
+
+        if self.signed.contains(&digest) {
+            return Err(Error::new(
+                ErrorKind::ProviderError,
+                Some("message has already been signed"),
+            ));
+        }
+
+        let signature = self.signer.sign(msg)?;
+        self.signed.insert(digest);
+        Ok(signature)
+    }
+
+    /// Has this wrapper already signed the given message?
+    pub fn has_signed(&self, msg: &[u8]) -> bool {
+        self.signed.contains(&message_digest(msg))
+    }
+
+    /// Borrow the wrapped signer
+    pub fn inner(&self) -> &S {
+        &self.signer
+    }
+}
+
+impl<S> PublicKeyed<ed25519::PublicKey> for OnceSigner<S>
+where
+    S: Signer<ed25519::Signature> + PublicKeyed<ed25519::PublicKey>,
+{
+    fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
+        self.signer.public_key()
+    }
+}
+
+/// Compute the SHA-256 digest under which a message is recorded
+fn message_digest(msg: &[u8]) -> [u8; 32] {
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(Sha256::digest(msg).as_slice());
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnceSigner;
+    use crate::Ed25519Signer;
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        error::ErrorKind,
+        Signer,
+    };
+
+    #[test]
+    fn rejects_repeated_message() {
+        let signer = Ed25519Signer::from(&Seed::from_bytes(TEST_VECTORS[0].sk).unwrap());
+        let expected = signer.sign(b"first").unwrap();
+        let mut once_signer = OnceSigner::new(signer);
+
+        assert!(!once_signer.has_signed(b"first"));
+        assert_eq!(once_signer.sign(b"first").unwrap(), expected);
+        assert!(once_signer.has_signed(b"first"));
+
+        let err = once_signer.sign(b"first").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+
+        assert!(once_signer.sign(b"second").is_ok());
+        assert!(once_signer.sign(b"second").is_err());
+    }
+}
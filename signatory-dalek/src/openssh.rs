@@ -0,0 +1,127 @@
+//! OpenSSH public key support (i.e. `authorized_keys` lines as described in
+//! RFC 4253 Section 6.6 and RFC 8709)
+
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+use subtle_encoding::{Base64, Encoding};
+
+use crate::{decode_public_key, Ed25519Verifier};
+
+/// OpenSSH key type identifier for Ed25519 keys
+const KEY_TYPE: &str = "ssh-ed25519";
+
+/// Size of an encoded Ed25519 OpenSSH public key blob: a length-prefixed key
+/// type followed by a length-prefixed public key
+const BLOB_SIZE: usize = 4 + 11 + 4 + ed25519::PUBLIC_KEY_SIZE;
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from an OpenSSH public key line of the
+    /// form `ssh-ed25519 <base64 blob> [comment]` (e.g. as produced by
+    /// `ssh-keygen` or found in `authorized_keys`).
+    ///
+    /// Returns `ParseError` if the line is not an `ssh-ed25519` key or the
+    /// blob is malformed, and `KeyInvalid` if the public key is not a valid
+    /// Edwards point.
+    pub fn from_openssh(line: &str) -> Result<Self, Error> {
+        let mut fields = line.split_whitespace();
+
+        if fields.next() != Some(KEY_TYPE) {
+            return Err(parse_error("expected ssh-ed25519 key type"));
+        }
+
+        let encoded = fields
+            .next()
+            .ok_or_else(|| parse_error("missing OpenSSH key blob"))?;
+
+        let mut blob = [0u8; BLOB_SIZE];
+        let len = Base64::default()
+            .decode_to_slice(encoded.as_bytes(), &mut blob)
+            .map_err(|_| parse_error("malformed OpenSSH key blob"))?;
+
+        let mut input = &blob[..len];
+
+        if read_string(&mut input)? != KEY_TYPE.as_bytes() {
+            return Err(parse_error("mismatched OpenSSH key type"));
+        }
+
+        let public_key = read_string(&mut input)?;
+
+        if public_key.len() != ed25519::PUBLIC_KEY_SIZE || !input.is_empty() {
+            return Err(parse_error("expected 32-byte Ed25519 public key"));
+        }
+
+        decode_public_key(public_key).map(Ed25519Verifier)
+    }
+}
+
+/// Read a length-prefixed (i.e. SSH `string`) value, advancing the input
+fn read_string<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+    if input.len() < 4 {
+        return Err(parse_error("truncated OpenSSH key blob"));
+    }
+
+    let len = (input[0] as usize) << 24
+        | (input[1] as usize) << 16
+        | (input[2] as usize) << 8
+        | input[3] as usize;
+
+    if input.len() - 4 < len {
+        return Err(parse_error("truncated OpenSSH key blob"));
+    }
+
+    let value = &input[4..4 + len];
+    *input = &input[4 + len..];
+    Ok(value)
+}
+
+/// Create a `ParseError` with the given description
+fn parse_error(description: &str) -> Error {
+    Error::new(ErrorKind::ParseError, Some(description))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Verifier;
+    use signatory::error::ErrorKind;
+
+    /// Public key generated by `ssh-keygen -t ed25519 -C alice@example.com`
+    const SSH_KEYGEN_PUBLIC_KEY: &str = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIAJOjA6uynedE/KaEyDVmcVzE7JRJl7cVqjlaPzldYu4 alice@example.com\n";
+
+    /// Raw public key encoded in `SSH_KEYGEN_PUBLIC_KEY`
+    const SSH_KEYGEN_PUBLIC_KEY_HEX: &str =
+        "024e8c0eaeca779d13f29a1320d599c57313b251265edc56a8e568fce5758bb8";
+
+    #[test]
+    fn ssh_keygen_public_key() {
+        let verifier = Ed25519Verifier::from_openssh(SSH_KEYGEN_PUBLIC_KEY).unwrap();
+        assert_eq!(
+            verifier,
+            Ed25519Verifier::from_hex(SSH_KEYGEN_PUBLIC_KEY_HEX).unwrap()
+        );
+
+        // The comment is optional
+        let line = SSH_KEYGEN_PUBLIC_KEY
+            .trim_end()
+            .trim_end_matches("alice@example.com");
+        assert_eq!(Ed25519Verifier::from_openssh(line).unwrap(), verifier);
+    }
+
+    #[test]
+    fn malformed_openssh_keys() {
+        for line in &[
+            "",
+            "ssh-ed25519",
+            "ssh-rsa AAAAC3NzaC1lZDI1NTE5AAAAIAJOjA6uynedE/KaEyDVmcVzE7JRJl7cVqjlaPzldYu4",
+            "ssh-ed25519 !!!!",
+            // Blob truncated to a 31-byte public key
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAHwJOjA6uynedE/KaEyDVmcVzE7JRJl7cVqjlaPzldYs=",
+            // Blob for a different key type (`ssh-ed25518`)
+            "ssh-ed25519 AAAAC3NzaC1lZDI1NTE4AAAAIAJOjA6uynedE/KaEyDVmcVzE7JRJl7cVqjlaPzldYu4",
+        ] {
+            let err = Ed25519Verifier::from_openssh(line).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError, "{}", line);
+        }
+    }
+}
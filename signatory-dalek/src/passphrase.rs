@@ -0,0 +1,88 @@
+//! Deterministic derivation of Ed25519 signers from passphrases
+//!
+//! Seeds are derived with PBKDF2-HMAC-SHA512 (RFC 8018 Section 5.2) using
+//! the parameters below. These parameters are part of the derivation: changing
+//! any of them would change the key derived from every passphrase, so they
+//! will never change within this crate.
+
+use hmac::Hmac;
+use sha2::Sha512;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+
+use crate::{clear_bytes, Ed25519Signer};
+
+/// Number of PBKDF2 iterations (the OWASP recommendation for
+/// PBKDF2-HMAC-SHA512 at the time of writing)
+pub const PBKDF2_ITERATIONS: usize = 210_000;
+
+/// Minimum salt size in bytes (128 bits, per NIST SP 800-132)
+pub const MIN_SALT_SIZE: usize = 16;
+
+impl Ed25519Signer {
+    /// Derive an Ed25519 signer from a passphrase and salt. The seed is the
+    /// 32-byte output of PBKDF2-HMAC-SHA512 with `PBKDF2_ITERATIONS`
+    /// iterations, so the same passphrase and salt always yield the same key.
+    ///
+    /// Returns `ParseError` if the salt is shorter than `MIN_SALT_SIZE`.
+    ///
+    /// Passphrases chosen by humans are low-entropy: anyone who learns the
+    /// public key can mount an offline guessing attack against the
+    /// passphrase, slowed down only by the iteration count. The salt should be
+    /// unique per key to prevent precomputation.
+    pub fn from_passphrase(passphrase: &[u8], salt: &[u8]) -> Result<Self, Error> {
+        if salt.len() < MIN_SALT_SIZE {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("salt must be at least 16 bytes"),
+            ));
+        }
+
+        let mut seed = [0u8; ed25519::SEED_SIZE];
+        pbkdf2::pbkdf2::<Hmac<Sha512>>(passphrase, salt, PBKDF2_ITERATIONS, &mut seed);
+
+        let result = Self::from_seed(&ed25519::Seed::new(seed));
+        clear_bytes(&mut seed);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Signer;
+    use signatory::{error::ErrorKind, PublicKeyed};
+
+    const PASSPHRASE: &[u8] = b"correct horse battery staple";
+    const SALT: &[u8] = b"signatory-salt-0";
+
+    /// PBKDF2-HMAC-SHA512(PASSPHRASE, SALT, 210000, 32) as computed by
+    /// Python's `hashlib.pbkdf2_hmac`
+    const EXPECTED_SEED: [u8; 32] = [
+        0xfb, 0xef, 0xbe, 0x3b, 0x76, 0xe5, 0x74, 0x35, 0xc9, 0x64, 0xec, 0x77, 0x1b, 0xa7, 0x76,
+        0xfc, 0xbe, 0x84, 0x6f, 0x16, 0x0f, 0xc5, 0xd0, 0xea, 0x2e, 0x11, 0x11, 0x83, 0x23, 0x1a,
+        0xe8, 0x34,
+    ];
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let signer = Ed25519Signer::from_passphrase(PASSPHRASE, SALT).unwrap();
+        assert_eq!(
+            signer.to_seed().unwrap().as_secret_slice(),
+            &EXPECTED_SEED[..]
+        );
+
+        let rederived = Ed25519Signer::from_passphrase(PASSPHRASE, SALT).unwrap();
+        assert_eq!(
+            signer.public_key().unwrap(),
+            rederived.public_key().unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_short_salt() {
+        let result = Ed25519Signer::from_passphrase(PASSPHRASE, &SALT[..15]);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::ParseError);
+    }
+}
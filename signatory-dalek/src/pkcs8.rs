@@ -0,0 +1,178 @@
+//! PKCS#8 private key support (RFC 5958 `OneAsymmetricKey` structures for
+//! Ed25519 as described in RFC 8410)
+
+use alloc::vec::Vec;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    PublicKeyed,
+};
+
+use crate::{
+    der::{
+        ensure_empty, parse_error, read_ed25519_algorithm, read_tlv, write_ed25519_algorithm,
+        TAG_SEQUENCE,
+    },
+    Ed25519Signer,
+};
+
+/// DER tags used by `OneAsymmetricKey`, including the context-specific tags
+/// of its optional fields
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_ATTRIBUTES: u8 = 0xa0;
+const TAG_PUBLIC_KEY: u8 = 0x81;
+
+impl Ed25519Signer {
+    /// Create a new Ed25519 signer from a DER-encoded PKCS#8 private key
+    /// (e.g. as generated by `openssl genpkey -algorithm ed25519`).
+    ///
+    /// Returns `ParseError` if the key is malformed or its algorithm is not
+    /// Ed25519, and `KeyInvalid` if it contains a public key which does not
+    /// correspond to its seed.
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+        let mut input = der;
+        let mut key = read_tlv(&mut input, TAG_SEQUENCE)?;
+        ensure_empty(input)?;
+
+        let version = read_tlv(&mut key, TAG_INTEGER)?;
+        if version != [0] && version != [1] {
+            return Err(parse_error("unsupported PKCS#8 version"));
+        }
+
+        read_ed25519_algorithm(&mut key)?;
+
+        let mut private_key = read_tlv(&mut key, TAG_OCTET_STRING)?;
+        let seed_bytes = read_tlv(&mut private_key, TAG_OCTET_STRING)?;
+        ensure_empty(private_key)?;
+
+        let seed = ed25519::Seed::from_bytes(seed_bytes)
+            .map_err(|_| parse_error("expected 32-byte Ed25519 seed"))?;
+        let signer = Self::from_seed(&seed)?;
+
+        if key.first() == Some(&TAG_ATTRIBUTES) {
+            read_tlv(&mut key, TAG_ATTRIBUTES)?;
+        }
+
+        if !key.is_empty() {
+            let public_key = read_tlv(&mut key, TAG_PUBLIC_KEY)?;
+
+            // The public key is a BIT STRING with no unused bits
+            if public_key.first() != Some(&0) {
+                return Err(parse_error("malformed public key"));
+            }
+
+            if public_key[1..] != *signer.public_key()?.as_bytes() {
+                return Err(Error::new(
+                    ErrorKind::KeyInvalid,
+                    Some("public key does not match seed"),
+                ));
+            }
+
+            ensure_empty(key)?;
+        }
+
+        Ok(signer)
+    }
+
+    /// Serialize this signer as a DER-encoded PKCS#8 v1 private key.
+    ///
+    /// The returned bytes contain secret key material: the caller is
+    /// responsible for zeroizing them once they are no longer needed.
+    pub fn to_pkcs8_der(&self) -> Result<Vec<u8>, Error> {
+        let seed = self.to_seed()?;
+        let mut der = Vec::with_capacity(48);
+        der.extend_from_slice(&[TAG_SEQUENCE, 0x2e, TAG_INTEGER, 0x01, 0x00]);
+        write_ed25519_algorithm(&mut der);
+        der.extend_from_slice(&[TAG_OCTET_STRING, 0x22, TAG_OCTET_STRING, 0x20]);
+        der.extend_from_slice(seed.as_secret_slice());
+        Ok(der)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Signer;
+    use signatory::{error::ErrorKind, PublicKeyed, Signer};
+
+    /// Ed25519 private key generated by `openssl genpkey -algorithm ed25519`
+    const OPENSSL_PKCS8_KEY: &[u8] = &[
+        0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04,
+        0x20, 0xaf, 0x7d, 0x9f, 0x67, 0xec, 0x2c, 0xfd, 0x6c, 0x98, 0x3c, 0x8e, 0x57, 0x07, 0xeb,
+        0xd0, 0x96, 0x81, 0x6b, 0x66, 0xce, 0x31, 0xab, 0x4a, 0x02, 0xa9, 0x18, 0xd7, 0x05, 0xe3,
+        0xc3, 0xc9, 0x9d,
+    ];
+
+    /// Public key for `OPENSSL_PKCS8_KEY`
+    const OPENSSL_PUBLIC_KEY: &[u8] = &[
+        0x54, 0x5c, 0xd5, 0x30, 0xb1, 0x69, 0x27, 0x8e, 0xaa, 0x08, 0x27, 0x58, 0x1f, 0x17, 0xd2,
+        0xc9, 0xe6, 0x19, 0xd5, 0xd6, 0x37, 0x07, 0x68, 0x84, 0x08, 0x00, 0x4f, 0x12, 0xd5, 0x32,
+        0x5b, 0x02,
+    ];
+
+    const OPENSSL_MESSAGE: &[u8] = b"hello pkcs8";
+
+    /// Signature over `OPENSSL_MESSAGE` produced by `openssl pkeyutl -sign`
+    const OPENSSL_SIGNATURE: &[u8] = &[
+        0xc4, 0x17, 0x98, 0x14, 0x6f, 0x55, 0x39, 0xd1, 0x6f, 0x8a, 0x8f, 0x7e, 0x0a, 0x1e, 0x1f,
+        0xf8, 0x50, 0x1d, 0xd0, 0xa0, 0x55, 0x8c, 0xed, 0x6a, 0x41, 0x26, 0xc2, 0x23, 0x67, 0xc8,
+        0xc9, 0xce, 0x53, 0xcf, 0x25, 0x97, 0x9c, 0xd9, 0xb8, 0x68, 0x36, 0x5b, 0xf4, 0x5c, 0x3a,
+        0x59, 0x1f, 0x6f, 0xd5, 0x5f, 0x94, 0x2e, 0xda, 0x72, 0xf4, 0xbc, 0x95, 0x95, 0xa7, 0x7a,
+        0xfb, 0x43, 0x43, 0x00,
+    ];
+
+    #[test]
+    fn openssl_key_round_trip() {
+        let signer = Ed25519Signer::from_pkcs8_der(OPENSSL_PKCS8_KEY).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), OPENSSL_PUBLIC_KEY);
+
+        let signature = signer.sign(OPENSSL_MESSAGE).unwrap();
+        assert_eq!(signature.as_ref(), OPENSSL_SIGNATURE);
+
+        assert_eq!(signer.to_pkcs8_der().unwrap(), OPENSSL_PKCS8_KEY);
+    }
+
+    #[test]
+    fn v2_key_with_public_key() {
+        let mut der = OPENSSL_PKCS8_KEY.to_vec();
+        der[1] += 2 + 1 + OPENSSL_PUBLIC_KEY.len() as u8;
+        der[4] = 1;
+        der.extend_from_slice(&[0x81, 0x21, 0x00]);
+        der.extend_from_slice(OPENSSL_PUBLIC_KEY);
+
+        let signer = Ed25519Signer::from_pkcs8_der(&der).unwrap();
+        assert_eq!(signer.public_key().unwrap().as_ref(), OPENSSL_PUBLIC_KEY);
+
+        // Corrupt the public key
+        *der.last_mut().unwrap() ^= 1;
+        let err = Ed25519Signer::from_pkcs8_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn wrong_algorithm_oid() {
+        // 1.3.101.110 (X25519)
+        let mut der = OPENSSL_PKCS8_KEY.to_vec();
+        der[11] = 0x6e;
+
+        let err = Ed25519Signer::from_pkcs8_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn malformed_der() {
+        for der in &[
+            &OPENSSL_PKCS8_KEY[..0],
+            &OPENSSL_PKCS8_KEY[..OPENSSL_PKCS8_KEY.len() - 1],
+            &[0x04, 0x20][..],
+        ] {
+            let err = Ed25519Signer::from_pkcs8_der(der).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        let mut der = OPENSSL_PKCS8_KEY.to_vec();
+        der.push(0);
+        let err = Ed25519Signer::from_pkcs8_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+}
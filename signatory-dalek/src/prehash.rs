@@ -0,0 +1,74 @@
+//! Adapter for signing and verifying already-finalized Ed25519ph prehashes
+
+use digest::{FixedOutput, Input, Reset};
+use signatory::{
+    error::{Error, ErrorKind},
+    generic_array::{typenum::U64, GenericArray},
+};
+
+/// Size of an Ed25519ph prehash (i.e. a SHA-512 digest) in bytes
+pub(crate) const PREHASH_SIZE: usize = 64;
+
+/// Digest which has already been finalized, allowing a 64-byte prehash to be
+/// passed to ed25519-dalek's `Digest`-based Ed25519ph API.
+///
+/// ed25519-dalek only ever calls `result()` on the digests it is given, so
+/// this type cannot absorb further input.
+#[derive(Clone, Default)]
+pub(crate) struct Prehash(GenericArray<u8, U64>);
+
+impl Prehash {
+    /// Wrap the given prehash, returning `ParseError` if it is not
+    /// `PREHASH_SIZE` bytes long
+    pub(crate) fn new(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != PREHASH_SIZE {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("prehash must be 64 bytes"),
+            ));
+        }
+
+        Ok(Prehash(GenericArray::clone_from_slice(bytes)))
+    }
+}
+
+impl Input for Prehash {
+    fn input<B: AsRef<[u8]>>(&mut self, _data: B) {
+        unreachable!("prehash has already been finalized");
+    }
+}
+
+impl FixedOutput for Prehash {
+    type OutputSize = U64;
+
+    fn fixed_result(self) -> GenericArray<u8, U64> {
+        self.0
+    }
+}
+
+impl Reset for Prehash {
+    fn reset(&mut self) {
+        unreachable!("prehash has already been finalized");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prehash, PREHASH_SIZE};
+    use digest::Digest;
+    use signatory::error::ErrorKind;
+
+    #[test]
+    fn result_returns_prehash() {
+        let bytes = [0x42; PREHASH_SIZE];
+        assert_eq!(&Prehash::new(&bytes).unwrap().result()[..], &bytes[..]);
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        for len in &[0, PREHASH_SIZE - 1, PREHASH_SIZE + 1] {
+            let err = Prehash::new(&vec![0x42; *len]).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Streaming Ed25519ph verification of `std::io::Read` sources
+
+use digest::Digest;
+use signatory::generic_array::typenum::U64;
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+};
+use std::io::{self, Read};
+
+use crate::{decode_signature, DefaultPhDigest, Ed25519Verifier};
+
+/// Size of the buffer used to read from readers
+const BUFFER_SIZE: usize = 8192;
+
+impl Ed25519Verifier {
+    /// Verify an Ed25519ph signature (with an empty context) over the
+    /// contents of the given reader, hashing them with SHA-512 as they are
+    /// read so the input never needs to be held in memory in its entirety.
+    ///
+    /// Ed25519ph signatures are produced by `Ed25519PhSigner` (e.g. with
+    /// `sign_message` or an `Ed25519PhWriter`): pure Ed25519 signatures over
+    /// the same data will be rejected, as the two schemes are deliberately
+    /// incompatible.
+    ///
+    /// Returns `Io` if reading fails, and `SignatureInvalid` if the signature
+    /// does not verify.
+    pub fn verify_reader<R: Read>(&self, reader: R, sig: &ed25519::Signature) -> Result<(), Error> {
+        self.verify_reader_ph::<R, DefaultPhDigest>(reader, sig)
+    }
+
+    /// Verify an Ed25519ph signature (with an empty context) over the
+    /// contents of the given reader as in `verify_reader`, hashing them with
+    /// the digest `D`.
+    ///
+    /// The reader is consumed in fixed-size chunks, so memory use is bounded
+    /// regardless of the length of the input.
+    pub fn verify_reader_ph<R, D>(
+        &self,
+        mut reader: R,
+        sig: &ed25519::Signature,
+    ) -> Result<(), Error>
+    where
+        R: Read,
+        D: Digest<OutputSize = U64> + Default,
+    {
+        let mut digest = D::new();
+        let mut buffer = [0u8; BUFFER_SIZE];
+
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(len) => digest.input(&buffer[..len]),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => return Err(Error::new(ErrorKind::Io, Some("error reading message"))),
+            }
+        }
+
+        let dalek_sig = decode_signature(sig)?;
+
+        self.0
+            .verify_prehashed(digest, None, &dalek_sig)
+            .map_err(|_| ErrorKind::SignatureInvalid.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519PhSigner, Ed25519Signer, Ed25519Verifier};
+    use sha2::{Digest, Sha512};
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed, Signer,
+    };
+    use std::io;
+
+    #[test]
+    fn verifies_large_reader() {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[0].sk).unwrap());
+        let verifier = Ed25519Verifier::from_public_key(&signer.public_key().unwrap()).unwrap();
+        let message: std::vec::Vec<u8> = (0..1_000_000u32).map(|i| i as u8).collect();
+        let sig = signer.sign_message(&message).unwrap();
+
+        assert!(verifier.verify_reader(&message[..], &sig).is_ok());
+
+        let err = verifier.verify_reader(&message[1..], &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn verifies_large_reader_with_digest() {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[1].sk).unwrap());
+        let verifier = Ed25519Verifier::from_public_key(&signer.public_key().unwrap()).unwrap();
+        let message: std::vec::Vec<u8> = (0..1_000_000u32).map(|i| (i % 251) as u8).collect();
+        let sig = signer
+            .sign_prehash(Sha512::digest(&message).as_slice())
+            .unwrap();
+
+        assert!(verifier
+            .verify_reader_ph::<_, Sha512>(&message[..], &sig)
+            .is_ok());
+
+        let err = verifier
+            .verify_reader_ph::<_, Sha512>(&message[..message.len() - 1], &sig)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_pure_ed25519_signature() {
+        let vector = &TEST_VECTORS[2];
+        let signer = Ed25519Signer::from(&Seed::from_bytes(vector.sk).unwrap());
+        let verifier = Ed25519Verifier::from_public_key(&signer.public_key().unwrap()).unwrap();
+        let sig = signer.sign(vector.msg).unwrap();
+
+        let err = verifier.verify_reader(vector.msg, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn reports_read_errors() {
+        struct FailingReader;
+
+        impl io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        let vector = &TEST_VECTORS[0];
+        let verifier = Ed25519Verifier::from_bytes(vector.pk).unwrap();
+        let sig = signatory::Signature::from_bytes(vector.sig).unwrap();
+
+        let err = verifier.verify_reader(FailingReader, &sig).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Io);
+    }
+}
@@ -0,0 +1,72 @@
+//! Structured metadata about signing operations, e.g. for audit logs
+
+use signatory::{ed25519, error::Error, Signer};
+
+use crate::Ed25519Signer;
+
+/// Record of a signing operation, describing what was signed without
+/// including the message itself
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SigningReceipt {
+    /// Public key the signature verifies under
+    pub public_key: ed25519::PublicKey,
+
+    /// Length of the signed message in bytes
+    pub message_len: usize,
+
+    /// Caller-provided time of signing, in whatever units the caller uses
+    /// (e.g. seconds since the Unix epoch)
+    pub timestamp: Option<u64>,
+}
+
+impl Ed25519Signer {
+    /// Sign a message, returning the signature together with a receipt
+    /// recording the public key, message length, and the given time of
+    /// signing (which this crate never reads from a clock itself)
+    pub fn sign_with_receipt(
+        &self,
+        msg: &[u8],
+        now: Option<u64>,
+    ) -> Result<(ed25519::Signature, SigningReceipt), Error> {
+        let signature = self.sign(msg)?;
+        let receipt = SigningReceipt {
+            public_key: ed25519::PublicKey::new(*self.public.as_bytes()),
+            message_len: msg.len(),
+            timestamp: now,
+        };
+
+        Ok((signature, receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use signatory::{ed25519::TEST_VECTORS, PublicKeyed, Verifier};
+
+    #[test]
+    fn receipt_public_key_verifies_signature() {
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let (sig, receipt) = signer
+                .sign_with_receipt(vector.msg, Some(1_554_000_000))
+                .unwrap();
+
+            assert_eq!(receipt.public_key, signer.public_key().unwrap());
+            assert_eq!(receipt.message_len, vector.msg.len());
+            assert_eq!(receipt.timestamp, Some(1_554_000_000));
+
+            let verifier = Ed25519Verifier::from_public_key(&receipt.public_key).unwrap();
+            assert!(verifier.verify(vector.msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn receipt_without_timestamp() {
+        let signer = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let (_, receipt) = signer.sign_with_receipt(b"", None).unwrap();
+        assert_eq!(receipt.message_len, 0);
+        assert_eq!(receipt.timestamp, None);
+    }
+}
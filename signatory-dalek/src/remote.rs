@@ -0,0 +1,107 @@
+//! Adapter for Ed25519 signers whose private key lives outside this process,
+//! e.g. in a network HSM
+
+use core::fmt::{self, Debug};
+use signatory::{ed25519, error::Error, PublicKeyed, Signer};
+
+use crate::{fmt_hex, SIGNATURE_LENGTH};
+
+/// Ed25519 signer which forwards signing requests to a user-supplied
+/// function, e.g. one which calls out to a remote HSM.
+///
+/// The public key is supplied at construction, so the private key never
+/// needs to be present in this process. The signatures returned by the
+/// function are passed through as-is: this adapter does not check that they
+/// verify under the public key.
+pub struct RemoteEd25519Signer<F>
+where
+    F: Fn(&[u8]) -> Result<[u8; SIGNATURE_LENGTH], Error> + Send + Sync,
+{
+    /// Public key corresponding to the remote private key
+    public_key: ed25519::PublicKey,
+
+    /// Function which produces a signature over a message
+    sign_fn: F,
+}
+
+impl<F> RemoteEd25519Signer<F>
+where
+    F: Fn(&[u8]) -> Result<[u8; SIGNATURE_LENGTH], Error> + Send + Sync,
+{
+    /// Create a new remote signer for the given public key, which signs
+    /// messages by calling `sign_fn`
+    pub fn new(public_key: ed25519::PublicKey, sign_fn: F) -> Self {
+        RemoteEd25519Signer {
+            public_key,
+            sign_fn,
+        }
+    }
+}
+
+impl<F> Debug for RemoteEd25519Signer<F>
+where
+    F: Fn(&[u8]) -> Result<[u8; SIGNATURE_LENGTH], Error> + Send + Sync,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RemoteEd25519Signer {{ public_key: ")?;
+        fmt_hex(f, self.public_key.as_bytes())?;
+        write!(f, " }}")
+    }
+}
+
+impl<F> PublicKeyed<ed25519::PublicKey> for RemoteEd25519Signer<F>
+where
+    F: Fn(&[u8]) -> Result<[u8; SIGNATURE_LENGTH], Error> + Send + Sync,
+{
+    fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl<F> Signer<ed25519::Signature> for RemoteEd25519Signer<F>
+where
+    F: Fn(&[u8]) -> Result<[u8; SIGNATURE_LENGTH], Error> + Send + Sync,
+{
+    fn sign(&self, msg: &[u8]) -> Result<ed25519::Signature, Error> {
+        (self.sign_fn)(msg).map(ed25519::Signature::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoteEd25519Signer;
+    use crate::Ed25519Signer;
+    use core::convert::TryFrom;
+    use signatory::{
+        ed25519::TEST_VECTORS,
+        error::{Error, ErrorKind},
+        PublicKeyed, Signer,
+    };
+
+    #[test]
+    fn forwards_to_sign_fn() {
+        for vector in TEST_VECTORS {
+            let inner = Ed25519Signer::try_from(vector.sk).unwrap();
+            let remote = RemoteEd25519Signer::new(inner.public_key().unwrap(), |msg| {
+                Ok(*inner.sign(msg)?.as_bytes())
+            });
+
+            assert_eq!(remote.public_key().unwrap().as_bytes(), vector.pk);
+            assert_eq!(remote.sign(vector.msg).unwrap().as_bytes(), vector.sig);
+        }
+    }
+
+    #[test]
+    fn propagates_sign_fn_errors() {
+        let inner = Ed25519Signer::try_from(TEST_VECTORS[0].sk).unwrap();
+        let remote = RemoteEd25519Signer::new(inner.public_key().unwrap(), |_| {
+            Err(Error::new(
+                ErrorKind::ProviderError,
+                Some("HSM unavailable"),
+            ))
+        });
+
+        let err = remote.sign(TEST_VECTORS[0].msg).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+    }
+}
@@ -0,0 +1,107 @@
+//! Verification of many Ed25519 signatures, reporting which ones failed
+
+use alloc::vec::Vec;
+use signatory::{ed25519, Verifier};
+
+use crate::Ed25519Verifier;
+
+/// Verify many Ed25519 signatures over the given messages under the given
+/// public keys, reporting the (ascending) indices of any items which fail to
+/// verify, e.g. so they can be dropped from a block of transactions.
+///
+/// With the `batch` feature, the whole batch is verified at once with
+/// `verify_batch` first, so only if it fails are the items verified
+/// individually to locate the bad ones. Without it, every item is verified
+/// individually. If the slices are not all the same length, the items past
+/// the end of the shortest slice are incomplete and reported as failures.
+pub fn verify_batch_report(
+    messages: &[&[u8]],
+    signatures: &[ed25519::Signature],
+    public_keys: &[ed25519::PublicKey],
+) -> Result<(), Vec<usize>> {
+    #[cfg(feature = "batch")]
+    {
+        if crate::batch::verify_batch(messages, signatures, public_keys).is_ok() {
+            return Ok(());
+        }
+    }
+
+    let len = messages.len().min(signatures.len()).min(public_keys.len());
+    let max_len = messages.len().max(signatures.len()).max(public_keys.len());
+
+    let failures: Vec<usize> = (0..len)
+        .filter(|&i| {
+            Ed25519Verifier::from_public_key(&public_keys[i])
+                .and_then(|verifier| verifier.verify(messages[i], &signatures[i]))
+                .is_err()
+        })
+        .chain(len..max_len)
+        .collect();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_batch_report;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        Signature,
+    };
+    use std::vec::Vec;
+
+    /// Split the RFC 8032 test vectors into messages, signatures, and public keys
+    fn test_vector_batch() -> (
+        Vec<&'static [u8]>,
+        Vec<ed25519::Signature>,
+        Vec<ed25519::PublicKey>,
+    ) {
+        let messages = TEST_VECTORS.iter().map(|vector| vector.msg).collect();
+        let signatures = TEST_VECTORS
+            .iter()
+            .map(|vector| ed25519::Signature::from_bytes(vector.sig).unwrap())
+            .collect();
+        let public_keys = TEST_VECTORS
+            .iter()
+            .map(|vector| ed25519::PublicKey::from_bytes(vector.pk).unwrap())
+            .collect();
+
+        (messages, signatures, public_keys)
+    }
+
+    #[test]
+    fn reports_no_failures() {
+        let (messages, signatures, public_keys) = test_vector_batch();
+        assert_eq!(
+            verify_batch_report(&messages, &signatures, &public_keys),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn reports_failing_indices() {
+        let (messages, mut signatures, mut public_keys) = test_vector_batch();
+        signatures[3].0[0] ^= 0x42;
+        public_keys[1] = public_keys[0];
+
+        assert_eq!(
+            verify_batch_report(&messages, &signatures, &public_keys),
+            Err(vec![1, 3])
+        );
+    }
+
+    #[test]
+    fn reports_incomplete_items() {
+        let (messages, signatures, public_keys) = test_vector_batch();
+        let len = messages.len();
+
+        assert_eq!(
+            verify_batch_report(&messages, &signatures[..len - 1], &public_keys),
+            Err(vec![len - 1])
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! serde support for Ed25519 verifiers
+//!
+//! Public keys are serialized as raw bytes, or as lower-case hexadecimal
+//! strings when the serialization format is human-readable (e.g. JSON).
+
+use core::{fmt, str};
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use signatory::ed25519::PUBLIC_KEY_SIZE;
+use subtle_encoding::{Encoding, Hex};
+
+use crate::{decode_public_key, Ed25519PhVerifier, Ed25519Verifier};
+
+impl Serialize for Ed25519Verifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_public_key(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ed25519Verifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_public_key(deserializer).map(Ed25519Verifier)
+    }
+}
+
+/// Only the public key is serialized: deserialized verifiers have no context
+impl Serialize for Ed25519PhVerifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_public_key(&self.public_key, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ed25519PhVerifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Ed25519PhVerifier {
+            public_key: deserialize_public_key(deserializer)?,
+            context: None,
+        })
+    }
+}
+
+/// Serialize a Dalek public key as bytes, or hex if the format is human-readable
+fn serialize_public_key<S: Serializer>(
+    public_key: &ed25519_dalek::PublicKey,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut hex = [0u8; PUBLIC_KEY_SIZE * 2];
+        Hex::default()
+            .encode_to_slice(public_key.as_bytes(), &mut hex)
+            .unwrap();
+        serializer.serialize_str(str::from_utf8(&hex).unwrap())
+    } else {
+        serializer.serialize_bytes(public_key.as_bytes())
+    }
+}
+
+/// Deserialize a Dalek public key, rejecting invalid Edwards points
+fn deserialize_public_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ed25519_dalek::PublicKey, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(PublicKeyVisitor)
+    } else {
+        deserializer.deserialize_bytes(PublicKeyVisitor)
+    }
+}
+
+/// serde visitor for Ed25519 public keys
+struct PublicKeyVisitor;
+
+impl<'de> Visitor<'de> for PublicKeyVisitor {
+    type Value = ed25519_dalek::PublicKey;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {}-byte Ed25519 public key", PUBLIC_KEY_SIZE)
+    }
+
+    fn visit_str<E: de::Error>(self, hex: &str) -> Result<Self::Value, E> {
+        let mut bytes = [0u8; PUBLIC_KEY_SIZE];
+        let len = Hex::default()
+            .decode_to_slice(hex.as_bytes(), &mut bytes)
+            .map_err(|_| E::invalid_value(Unexpected::Str(hex), &self))?;
+
+        self.visit_bytes(&bytes[..len])
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        decode_public_key(bytes).map_err(|_| E::invalid_value(Unexpected::Bytes(bytes), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519PhVerifier, Ed25519Verifier};
+    use signatory::ed25519::{PublicKey, TEST_VECTORS};
+
+    /// Compressed Edwards-y encoding which does not decompress to a curve point
+    const INVALID_POINT_HEX: &str =
+        "\"0200000000000000000000000000000000000000000000000000000000000000\"";
+
+    fn test_verifier() -> Ed25519Verifier {
+        Ed25519Verifier::from(&PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap())
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let verifier = test_verifier();
+        let json = serde_json::to_string(&verifier).unwrap();
+        assert_eq!(
+            json,
+            "\"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Ed25519Verifier>(&json).unwrap(),
+            verifier
+        );
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let verifier = test_verifier();
+        let bytes = bincode::serialize(&verifier).unwrap();
+        assert_eq!(&bytes[bytes.len() - 32..], TEST_VECTORS[0].pk);
+        assert_eq!(
+            bincode::deserialize::<Ed25519Verifier>(&bytes).unwrap(),
+            verifier
+        );
+    }
+
+    #[test]
+    fn ph_verifier_round_trip() {
+        let verifier = Ed25519PhVerifier::from(&PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap());
+
+        let json = serde_json::to_string(&verifier).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Ed25519PhVerifier>(&json).unwrap(),
+            verifier
+        );
+
+        let bytes = bincode::serialize(&verifier).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Ed25519PhVerifier>(&bytes).unwrap(),
+            verifier
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_points() {
+        assert!(serde_json::from_str::<Ed25519Verifier>(INVALID_POINT_HEX).is_err());
+
+        let mut bytes = bincode::serialize(&test_verifier()).unwrap();
+        let len = bytes.len();
+        bytes[len - 32..].copy_from_slice(&[0u8; 32]);
+        bytes[len - 32] = 2;
+        assert!(bincode::deserialize::<Ed25519Verifier>(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert!(serde_json::from_str::<Ed25519Verifier>("\"not hex\"").is_err());
+        assert!(serde_json::from_str::<Ed25519Verifier>("\"d75a98\"").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! Implementations of the RustCrypto `signature` crate's key traits
+
+use crate::{Ed25519Signer, Ed25519Verifier};
+
+impl signature::Keypair for Ed25519Signer {
+    type VerifyingKey = Ed25519Verifier;
+
+    fn verifying_key(&self) -> Ed25519Verifier {
+        self.verifier()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Signer;
+    use core::convert::TryFrom;
+    use signatory::{ed25519::TEST_VECTORS, Signer, Verifier};
+
+    /// Generic code which only knows about `signature::Keypair`
+    fn verifying_key_of<K: signature::Keypair>(keypair: &K) -> K::VerifyingKey {
+        keypair.verifying_key()
+    }
+
+    #[test]
+    fn verifying_key_verifies_signatures() {
+        for vector in TEST_VECTORS {
+            let signer = Ed25519Signer::try_from(vector.sk).unwrap();
+            let verifier = verifying_key_of(&signer);
+
+            assert_eq!(verifier.as_ref(), vector.pk);
+            let sig = signer.sign(vector.msg).unwrap();
+            assert!(verifier.verify(vector.msg, &sig).is_ok());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Debug-level `tracing` spans around signing and verification
+
+use signatory::error::Error;
+use tracing::{field, span::Span, Level};
+
+use crate::Fingerprint;
+
+/// Create a span for an Ed25519 signing operation over a message of the given
+/// length, identifying the key by its public key fingerprint.
+///
+/// Neither secret key material nor message contents are ever recorded.
+pub(crate) fn sign_span(public_key: &ed25519_dalek::PublicKey, msg_len: usize) -> Span {
+    tracing::span!(
+        Level::DEBUG,
+        "ed25519_sign",
+        key = %Fingerprint::new(public_key),
+        msg_len,
+        outcome = field::Empty
+    )
+}
+
+/// Create a span for an Ed25519 verification of a signature over a message
+/// of the given length
+pub(crate) fn verify_span(public_key: &ed25519_dalek::PublicKey, msg_len: usize) -> Span {
+    tracing::span!(
+        Level::DEBUG,
+        "ed25519_verify",
+        key = %Fingerprint::new(public_key),
+        msg_len,
+        outcome = field::Empty
+    )
+}
+
+/// Record the outcome of the operation in the given span: either `ok` or the
+/// kind of error which occurred
+pub(crate) fn record_outcome<T>(span: &Span, result: &Result<T, Error>) {
+    match result {
+        Ok(_) => span.record("outcome", "ok"),
+        Err(e) => span.record("outcome", field::debug(e.kind())),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use signatory::{PublicKeyed, Signature, Signer, Verifier};
+    use std::{
+        collections::BTreeMap,
+        fmt::Debug,
+        string::{String, ToString},
+        sync::{Arc, Mutex},
+        vec::Vec,
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    /// Fields recorded for a single span
+    #[derive(Clone, Debug, Default)]
+    struct SpanRecord {
+        name: String,
+        fields: BTreeMap<String, String>,
+    }
+
+    impl Visit for SpanRecord {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.fields
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    /// Subscriber which records every span created while it is the default
+    #[derive(Clone, Default)]
+    struct TestSubscriber {
+        spans: Arc<Mutex<Vec<SpanRecord>>>,
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+            let mut record = SpanRecord {
+                name: attrs.metadata().name().to_string(),
+                ..SpanRecord::default()
+            };
+            attrs.record(&mut record);
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(record);
+            span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &span::Id, values: &span::Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut spans[id.into_u64() as usize - 1]);
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn verify_records_span_with_outcome() {
+        let signer = Ed25519Signer::try_from(&[0x2c; 32][..]).unwrap();
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+        let sig = signer.sign(b"traced").unwrap();
+
+        let mut tampered = sig.clone().into_bytes();
+        tampered[0] ^= 1;
+        let tampered = Signature::from_bytes(&tampered[..]).unwrap();
+
+        let subscriber = TestSubscriber::default();
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            assert!(verifier.verify(b"traced", &sig).is_ok());
+            assert!(verifier.verify(b"traced", &tampered).is_err());
+        });
+
+        let spans = subscriber.spans.lock().unwrap();
+        assert_eq!(spans.len(), 2);
+
+        for span in spans.iter() {
+            assert_eq!(span.name, "ed25519_verify");
+            assert_eq!(span.fields["key"], to_hex(&verifier.fingerprint()));
+            assert_eq!(span.fields["msg_len"], "6");
+        }
+
+        assert_eq!(spans[0].fields["outcome"], "ok");
+        assert_eq!(spans[1].fields["outcome"], "SignatureInvalid");
+    }
+
+    #[test]
+    fn sign_records_span() {
+        let signer = Ed25519Signer::try_from(&[0x2d; 32][..]).unwrap();
+
+        let subscriber = TestSubscriber::default();
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            signer.sign(b"traced").unwrap();
+        });
+
+        let spans = subscriber.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "ed25519_sign");
+        assert_eq!(spans[0].fields["outcome"], "ok");
+        assert!(!spans[0].fields.values().any(|v| v.contains("2d2d2d")));
+    }
+}
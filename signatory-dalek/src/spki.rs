@@ -0,0 +1,116 @@
+//! SubjectPublicKeyInfo (SPKI) public key support for Ed25519 as described
+//! in RFC 8410
+
+use alloc::vec::Vec;
+use signatory::error::Error;
+
+use crate::{
+    decode_public_key,
+    der::{
+        ensure_empty, parse_error, read_ed25519_algorithm, read_tlv, write_ed25519_algorithm,
+        TAG_SEQUENCE,
+    },
+    Ed25519Verifier,
+};
+
+/// DER tag for the BIT STRING containing the public key
+const TAG_BIT_STRING: u8 = 0x03;
+
+impl Ed25519Verifier {
+    /// Create a new Ed25519 verifier from a DER-encoded SubjectPublicKeyInfo
+    /// structure, as found in X.509 certificates.
+    ///
+    /// Returns `ParseError` if the structure is malformed, its algorithm is
+    /// not Ed25519, or it is followed by trailing data, and `KeyInvalid` if
+    /// the public key is not a valid Edwards point.
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+        let mut input = der;
+        let mut spki = read_tlv(&mut input, TAG_SEQUENCE)?;
+        ensure_empty(input)?;
+
+        read_ed25519_algorithm(&mut spki)?;
+        let public_key = read_tlv(&mut spki, TAG_BIT_STRING)?;
+        ensure_empty(spki)?;
+
+        // The public key is a BIT STRING with no unused bits
+        match public_key.split_first() {
+            Some((0, bytes)) => decode_public_key(bytes).map(Ed25519Verifier),
+            _ => Err(parse_error("malformed public key")),
+        }
+    }
+
+    /// Serialize this verifier's public key as a DER-encoded
+    /// SubjectPublicKeyInfo structure
+    pub fn to_spki_der(&self) -> Result<Vec<u8>, Error> {
+        let mut der = Vec::with_capacity(44);
+        der.extend_from_slice(&[TAG_SEQUENCE, 0x2a]);
+        write_ed25519_algorithm(&mut der);
+        der.extend_from_slice(&[TAG_BIT_STRING, 0x21, 0x00]);
+        der.extend_from_slice(self.0.as_bytes());
+        Ok(der)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ed25519Verifier;
+    use signatory::{
+        ed25519::{PublicKey, TEST_VECTORS},
+        error::ErrorKind,
+    };
+
+    /// Public key generated by `openssl pkey -pubout -outform DER`
+    const OPENSSL_SPKI: &[u8] = &[
+        0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00, 0x54, 0x5c, 0xd5,
+        0x30, 0xb1, 0x69, 0x27, 0x8e, 0xaa, 0x08, 0x27, 0x58, 0x1f, 0x17, 0xd2, 0xc9, 0xe6, 0x19,
+        0xd5, 0xd6, 0x37, 0x07, 0x68, 0x84, 0x08, 0x00, 0x4f, 0x12, 0xd5, 0x32, 0x5b, 0x02,
+    ];
+
+    #[test]
+    fn spki_round_trip() {
+        let verifier = Ed25519Verifier::from(&PublicKey::from_bytes(TEST_VECTORS[0].pk).unwrap());
+        let der = verifier.to_spki_der().unwrap();
+        assert_eq!(Ed25519Verifier::from_spki_der(&der).unwrap(), verifier);
+    }
+
+    #[test]
+    fn openssl_spki() {
+        let verifier = Ed25519Verifier::from_spki_der(OPENSSL_SPKI).unwrap();
+        assert_eq!(
+            verifier,
+            Ed25519Verifier::from_bytes(&OPENSSL_SPKI[12..]).unwrap()
+        );
+        assert_eq!(verifier.to_spki_der().unwrap(), OPENSSL_SPKI);
+    }
+
+    #[test]
+    fn wrong_algorithm_oid() {
+        // 1.3.101.110 (X25519)
+        let mut der = OPENSSL_SPKI.to_vec();
+        der[8] = 0x6e;
+
+        let err = Ed25519Verifier::from_spki_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn algorithm_parameters_rejected() {
+        // AlgorithmIdentifier with a trailing NULL parameter
+        let mut der = OPENSSL_SPKI.to_vec();
+        der[1] += 2;
+        der[3] += 2;
+        der.splice(9..9, [0x05, 0x00].iter().cloned());
+
+        let err = Ed25519Verifier::from_spki_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn trailing_garbage_rejected() {
+        let mut der = OPENSSL_SPKI.to_vec();
+        der.push(0);
+
+        let err = Ed25519Verifier::from_spki_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+}
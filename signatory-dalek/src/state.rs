@@ -0,0 +1,99 @@
+//! Forkable incremental Ed25519ph verification state
+
+use digest::Digest;
+use signatory::{ed25519, error::Error, DigestVerifier};
+
+use crate::{DefaultPhDigest, Ed25519PhVerifier};
+
+/// SHA-512 accumulator for an Ed25519ph message which can be absorbed
+/// incrementally and verified with `Ed25519PhVerifier::verify_state`.
+///
+/// Cloning the state forks it: a common prefix can be hashed once, and each
+/// clone then finalized against a different candidate suffix without
+/// rehashing the prefix.
+#[derive(Clone, Default)]
+pub struct PrehashState(DefaultPhDigest);
+
+impl PrehashState {
+    /// Create a new, empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorb the given bytes into this state
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.input(data);
+    }
+
+    /// Absorb the given bytes into this state, returning it
+    pub fn chain(mut self, data: &[u8]) -> Self {
+        self.update(data);
+        self
+    }
+}
+
+impl Ed25519PhVerifier {
+    /// Verify an Ed25519ph signature over the message absorbed into the
+    /// given state, consuming it. Clone the state beforehand to verify
+    /// further candidates sharing the same prefix.
+    pub fn verify_state(&self, state: PrehashState, sig: &ed25519::Signature) -> Result<(), Error> {
+        self.verify(state.0, sig)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrehashState;
+    use crate::{Ed25519PhSigner, Ed25519PhVerifier};
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        error::ErrorKind,
+        PublicKeyed,
+    };
+
+    const PREFIX: &[u8] = b"shared message prefix, ";
+
+    fn signer_and_verifier() -> (Ed25519PhSigner, Ed25519PhVerifier) {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[0].sk).unwrap());
+        let verifier = Ed25519PhVerifier::from_public_key(&signer.public_key().unwrap()).unwrap();
+        (signer, verifier)
+    }
+
+    #[test]
+    fn forks_match_independent_hashing() {
+        let (signer, verifier) = signer_and_verifier();
+        let sig_a = signer
+            .sign_message(b"shared message prefix, suffix A")
+            .unwrap();
+        let sig_b = signer
+            .sign_message(b"shared message prefix, suffix B")
+            .unwrap();
+
+        let prefix = PrehashState::new().chain(PREFIX);
+        let fork_a = prefix.clone().chain(b"suffix A");
+        let fork_b = prefix.chain(b"suffix B");
+
+        assert!(verifier.verify_state(fork_a.clone(), &sig_a).is_ok());
+        assert!(verifier.verify_state(fork_b.clone(), &sig_b).is_ok());
+
+        let err = verifier.verify_state(fork_a, &sig_b).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        let err = verifier.verify_state(fork_b, &sig_a).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn incremental_updates_match_single_update() {
+        let (signer, verifier) = signer_and_verifier();
+        let sig = signer
+            .sign_message(b"shared message prefix, suffix")
+            .unwrap();
+
+        let mut state = PrehashState::new();
+        for byte in b"shared message prefix, suffix".iter() {
+            state.update(&[*byte]);
+        }
+
+        assert!(verifier.verify_state(state, &sig).is_ok());
+    }
+}
@@ -0,0 +1,209 @@
+//! Strict Ed25519 verification which rejects malleable signatures
+
+use core::hash::{Hash, Hasher};
+use curve25519_dalek::{edwards::CompressedEdwardsY, scalar::Scalar};
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Verifier,
+};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{decode_public_key, decode_signature, is_small_order};
+
+/// Ed25519 verifier provider for ed25519-dalek which performs additional
+/// checks beyond those of `Ed25519Verifier`.
+///
+/// `Ed25519Verifier` accepts any signature which satisfies the verification
+/// equation, which means a third party can produce additional valid signatures
+/// for the same message (e.g. by adding the group order to `s`). This verifier
+/// additionally rejects:
+///
+/// - signatures whose `s` component is not reduced modulo the group order
+/// - signatures whose `R` component is a small-order point
+/// - any signature under a small-order public key
+///
+/// This makes signatures non-malleable, which is important for consensus
+/// systems where every node must agree on exactly which signatures are valid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519StrictVerifier(ed25519_dalek::PublicKey);
+
+impl Ed25519StrictVerifier {
+    /// Create a new strict Ed25519 verifier from a public key, returning
+    /// `KeyInvalid` if it does not decode to a valid Edwards point
+    pub fn from_public_key(public_key: &ed25519::PublicKey) -> Result<Self, Error> {
+        decode_public_key(public_key.as_ref()).map(Ed25519StrictVerifier)
+    }
+}
+
+/// Deprecated: prefer `Ed25519StrictVerifier::from_public_key`, which returns
+/// `KeyInvalid` instead of panicking. This impl will be removed in a future
+/// release.
+///
+/// # Panics
+///
+/// Panics if the public key does not decode to a valid Edwards point.
+// `#[deprecated]` cannot be applied to trait impls, so this is documented only
+impl<'a> From<&'a ed25519::PublicKey> for Ed25519StrictVerifier {
+    fn from(public_key: &'a ed25519::PublicKey) -> Self {
+        Self::from_public_key(public_key).unwrap()
+    }
+}
+
+impl Hash for Ed25519StrictVerifier {
+    /// Hash the compressed public key, consistently with `PartialEq`
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_bytes().hash(state);
+    }
+}
+
+impl Verifier<ed25519::Signature> for Ed25519StrictVerifier {
+    fn verify(&self, msg: &[u8], sig: &ed25519::Signature) -> Result<(), Error> {
+        let (r_bytes, _) = signature_components(sig);
+
+        if !is_canonical_signature(sig)
+            || is_small_order(r_bytes)
+            || is_small_order(*self.0.as_bytes())
+        {
+            return Err(ErrorKind::SignatureInvalid.into());
+        }
+
+        let dalek_sig = decode_signature(sig)?;
+
+        self.0
+            .verify(msg, &dalek_sig)
+            .map_err(|_| ErrorKind::SignatureInvalid.into())
+    }
+}
+
+/// Is the given signature structurally well-formed? i.e. is its `s` component
+/// reduced modulo the group order, and does its `R` component decode to an
+/// Edwards point?
+///
+/// This is a cheap pre-screen which does not verify the signature: signatures
+/// failing it will never be accepted by `Ed25519StrictVerifier`.
+pub fn is_canonical_signature(sig: &ed25519::Signature) -> bool {
+    let (r_bytes, s_bytes) = signature_components(sig);
+
+    Scalar::from_canonical_bytes(s_bytes).is_some()
+        && CompressedEdwardsY(r_bytes).decompress().is_some()
+}
+
+/// Split a signature into the 32-byte encodings of its `R` point and `s`
+/// scalar, e.g. for diagnosing signature mismatches. The components are
+/// returned as-is, without checking that they decode.
+pub fn signature_components(sig: &ed25519::Signature) -> ([u8; 32], [u8; 32]) {
+    let mut r_bytes = [0u8; 32];
+    let mut s_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&sig.as_bytes()[..32]);
+    s_bytes.copy_from_slice(&sig.as_bytes()[32..]);
+    (r_bytes, s_bytes)
+}
+
+/// Compare all 64 bytes of two signatures in constant time, e.g. for
+/// protocols which treat a matching signature as a capability
+#[cfg(feature = "subtle")]
+pub fn signatures_ct_eq(a: &ed25519::Signature, b: &ed25519::Signature) -> Choice {
+    a.as_bytes()[..].ct_eq(&b.as_bytes()[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_canonical_signature, signature_components, Ed25519StrictVerifier};
+    use crate::Ed25519Verifier;
+    use signatory::{
+        ed25519::{self, TEST_VECTORS},
+        error::ErrorKind,
+        Signature, Verifier,
+    };
+
+    /// Order of the Ed25519 base point (little endian)
+    const BASEPOINT_ORDER: [u8; 32] = [
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde,
+        0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10,
+    ];
+
+    /// Make a malleated copy of a signature by adding the group order to `s`
+    fn malleate(sig: &[u8]) -> ed25519::Signature {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(sig);
+
+        let mut carry = 0u16;
+        for (byte, order_byte) in bytes[32..].iter_mut().zip(BASEPOINT_ORDER.iter()) {
+            let sum = u16::from(*byte) + u16::from(*order_byte) + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+
+        ed25519::Signature::new(bytes)
+    }
+
+    #[test]
+    fn verifies_rfc8032_test_vectors() {
+        for vector in TEST_VECTORS {
+            let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+            let verifier = Ed25519StrictVerifier::from(&pk);
+            assert!(verifier.verify(vector.msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_malleable_signature() {
+        let vector = &TEST_VECTORS[0];
+        let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+        let malleated_sig = malleate(vector.sig);
+
+        assert!(
+            Ed25519Verifier::from(&pk)
+                .verify(vector.msg, &malleated_sig)
+                .is_ok(),
+            "expected non-strict verifier to accept malleated signature"
+        );
+
+        let result = Ed25519StrictVerifier::from(&pk).verify(vector.msg, &malleated_sig);
+        assert_eq!(result.err().unwrap().kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn canonical_signatures() {
+        for vector in TEST_VECTORS {
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+            assert!(is_canonical_signature(&sig));
+        }
+    }
+
+    #[test]
+    fn non_canonical_s() {
+        assert!(!is_canonical_signature(&malleate(TEST_VECTORS[0].sig)));
+    }
+
+    #[test]
+    fn undecodable_r() {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(TEST_VECTORS[0].sig);
+
+        // y = 2 is not the y-coordinate of any point on the curve
+        bytes[..32].copy_from_slice(&[0u8; 32]);
+        bytes[0] = 2;
+
+        assert!(!is_canonical_signature(&ed25519::Signature::new(bytes)));
+    }
+
+    #[test]
+    fn signature_components_recombine() {
+        for vector in TEST_VECTORS {
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+            let (r_bytes, s_bytes) = signature_components(&sig);
+            assert_eq!(&r_bytes[..], &vector.sig[..32]);
+            assert_eq!(&s_bytes[..], &vector.sig[32..]);
+
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(&r_bytes);
+            bytes[32..].copy_from_slice(&s_bytes);
+            assert_eq!(ed25519::Signature::new(bytes), sig);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Verification of timestamped messages for replay protection
+
+use signatory::{
+    ed25519,
+    error::{Error, ErrorKind},
+    Verifier,
+};
+
+use crate::Ed25519Verifier;
+
+/// Size of the big-endian timestamp prefixed to timestamped messages
+pub const TIMESTAMP_SIZE: usize = 8;
+
+/// Ed25519 verifier for messages of the form `timestamp || payload`, where
+/// `timestamp` is an 8-byte big-endian integer, which rejects messages whose
+/// timestamp is too old (or in the future) to guard against replays.
+///
+/// The timestamp is opaque to this verifier: it only needs to be in the same
+/// units (e.g. seconds since the Unix epoch) as the `now` and `max_age`
+/// values passed to `verify_fresh`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimestampedVerifier(Ed25519Verifier);
+
+impl TimestampedVerifier {
+    /// Create a new timestamped verifier which checks signatures with the
+    /// given verifier
+    pub fn new(verifier: Ed25519Verifier) -> Self {
+        TimestampedVerifier(verifier)
+    }
+
+    /// Verify a signature over the whole of `signed` (timestamp included),
+    /// returning the payload following the timestamp if the signature is
+    /// valid and the timestamp is at most `max_age` before `now`.
+    ///
+    /// Returns `ParseError` if `signed` is too short to contain a timestamp,
+    /// and `SignatureInvalid` if the signature does not verify or the
+    /// timestamp is stale or in the future.
+    pub fn verify_fresh<'a>(
+        &self,
+        signed: &'a [u8],
+        sig: &ed25519::Signature,
+        now: u64,
+        max_age: u64,
+    ) -> Result<&'a [u8], Error> {
+        if signed.len() < TIMESTAMP_SIZE {
+            return Err(Error::new(
+                ErrorKind::ParseError,
+                Some("message is too short to contain a timestamp"),
+            ));
+        }
+
+        self.0.verify(signed, sig)?;
+
+        let (timestamp_bytes, payload) = signed.split_at(TIMESTAMP_SIZE);
+        let mut timestamp = [0u8; TIMESTAMP_SIZE];
+        timestamp.copy_from_slice(timestamp_bytes);
+        let timestamp = u64::from_be_bytes(timestamp);
+
+        match now.checked_sub(timestamp) {
+            Some(age) if age <= max_age => Ok(payload),
+            Some(_) => Err(Error::new(
+                ErrorKind::SignatureInvalid,
+                Some("timestamp is stale"),
+            )),
+            None => Err(Error::new(
+                ErrorKind::SignatureInvalid,
+                Some("timestamp is in the future"),
+            )),
+        }
+    }
+}
+
+impl From<Ed25519Verifier> for TimestampedVerifier {
+    fn from(verifier: Ed25519Verifier) -> Self {
+        Self::new(verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimestampedVerifier;
+    use crate::{Ed25519Signer, Ed25519Verifier};
+    use core::convert::TryFrom;
+    use signatory::{ed25519, error::ErrorKind, PublicKeyed, Signer};
+    use std::vec::Vec;
+
+    const PAYLOAD: &[u8] = b"payload";
+    const TIMESTAMP: u64 = 1_500_000_000;
+    const MAX_AGE: u64 = 300;
+
+    fn signed_message() -> (TimestampedVerifier, Vec<u8>, ed25519::Signature) {
+        let signer = Ed25519Signer::try_from(ed25519::TEST_VECTORS[0].sk).unwrap();
+        let verifier = Ed25519Verifier::from(&signer.public_key().unwrap());
+
+        let mut signed = TIMESTAMP.to_be_bytes().to_vec();
+        signed.extend_from_slice(PAYLOAD);
+        let sig = signer.sign(&signed).unwrap();
+
+        (TimestampedVerifier::new(verifier), signed, sig)
+    }
+
+    #[test]
+    fn accepts_fresh_message() {
+        let (verifier, signed, sig) = signed_message();
+
+        for &now in &[TIMESTAMP, TIMESTAMP + MAX_AGE] {
+            let payload = verifier.verify_fresh(&signed, &sig, now, MAX_AGE).unwrap();
+            assert_eq!(payload, PAYLOAD);
+        }
+    }
+
+    #[test]
+    fn rejects_stale_message() {
+        let (verifier, signed, sig) = signed_message();
+        let err = verifier
+            .verify_fresh(&signed, &sig, TIMESTAMP + MAX_AGE + 1, MAX_AGE)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_future_message() {
+        let (verifier, signed, sig) = signed_message();
+        let err = verifier
+            .verify_fresh(&signed, &sig, TIMESTAMP - 1, MAX_AGE)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_tampered_timestamp() {
+        let (verifier, mut signed, sig) = signed_message();
+        signed[7] ^= 1;
+        let err = verifier
+            .verify_fresh(&signed, &sig, TIMESTAMP, MAX_AGE)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn rejects_short_message() {
+        let (verifier, signed, sig) = signed_message();
+        let err = verifier
+            .verify_fresh(&signed[..7], &sig, TIMESTAMP, MAX_AGE)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+}
@@ -0,0 +1,116 @@
+//! Streaming Ed25519ph signing via `std::io::Write`
+
+use digest::Digest;
+use signatory::{ed25519, error::Error, generic_array::typenum::U64, DigestSigner};
+use std::io;
+
+use crate::{DefaultPhDigest, Ed25519PhSigner};
+
+/// Ed25519ph signer which absorbs the message to be signed incrementally
+/// into a SHA-512 digest (or any other 64-byte output digest `D`) via
+/// `std::io::Write`, e.g. with `io::copy`.
+///
+/// Signatures produced by `finalize` are identical to those produced by
+/// `Ed25519PhSigner::sign_message` for the same message.
+pub struct Ed25519PhWriter<D = DefaultPhDigest> {
+    /// Signer which signs the finalized digest
+    signer: Ed25519PhSigner,
+
+    /// Digest of the bytes written so far
+    digest: D,
+}
+
+impl Ed25519PhWriter {
+    /// Create a new writer which will sign everything written to it with the
+    /// given signer, hashing it with SHA-512
+    pub fn new(signer: Ed25519PhSigner) -> Self {
+        Self::with_digest(signer)
+    }
+}
+
+impl<D> Ed25519PhWriter<D>
+where
+    D: Digest<OutputSize = U64> + Default,
+{
+    /// Create a new writer which will sign everything written to it with the
+    /// given signer, hashing it with the digest `D`
+    pub fn with_digest(signer: Ed25519PhSigner) -> Self {
+        Ed25519PhWriter {
+            signer,
+            digest: D::new(),
+        }
+    }
+
+    /// Sign the bytes written so far, consuming the writer
+    pub fn finalize(self) -> Result<ed25519::Signature, Error> {
+        self.signer.sign(self.digest)
+    }
+}
+
+impl<D> io::Write for Ed25519PhWriter<D>
+where
+    D: Digest<OutputSize = U64> + Default,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.digest.input(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ed25519PhWriter;
+    use crate::Ed25519PhSigner;
+    use sha2::Sha512;
+    use signatory::ed25519::{Seed, TEST_VECTORS};
+    use std::io::{self, Write};
+
+    #[test]
+    fn writer_matches_sign_message() {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[0].sk).unwrap());
+        let message = [0x42u8; 10_000];
+
+        let mut writer = Ed25519PhWriter::new(signer.clone());
+        io::copy(&mut &message[..], &mut writer).unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            writer.finalize().unwrap(),
+            signer.sign_message(&message).unwrap()
+        );
+    }
+
+    #[test]
+    fn writer_with_partial_writes() {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[1].sk).unwrap());
+        let message = TEST_VECTORS[2].msg;
+
+        let mut writer = Ed25519PhWriter::new(signer.clone());
+        for chunk in message.chunks(1) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        assert_eq!(
+            writer.finalize().unwrap(),
+            signer.sign_message(message).unwrap()
+        );
+    }
+
+    #[test]
+    fn writer_with_explicit_digest() {
+        let signer = Ed25519PhSigner::from(&Seed::from_bytes(TEST_VECTORS[0].sk).unwrap());
+        let message = TEST_VECTORS[0].msg;
+
+        let mut writer = Ed25519PhWriter::<Sha512>::with_digest(signer.clone());
+        writer.write_all(message).unwrap();
+
+        assert_eq!(
+            writer.finalize().unwrap(),
+            signer.sign_message(message).unwrap()
+        );
+    }
+}
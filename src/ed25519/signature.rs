@@ -35,6 +35,12 @@ impl Signature {
     }
 }
 
+impl From<Signature> for [u8; SIGNATURE_SIZE] {
+    fn from(signature: Signature) -> [u8; SIGNATURE_SIZE] {
+        signature.into_bytes()
+    }
+}
+
 impl AsRef<[u8]> for Signature {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -81,6 +81,10 @@ impl From<ErrorKind> for Error {
 /// Kinds of errors
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum ErrorKind {
+    /// Invalid signature context, e.g. an Ed25519ph domain separation string
+    /// which is too long
+    ContextInvalid,
+
     /// Input/output error
     Io,
 
@@ -102,6 +106,7 @@ impl ErrorKind {
     /// bound to `std`
     pub fn as_str(self) -> &'static str {
         match self {
+            ErrorKind::ContextInvalid => "invalid signature context",
             ErrorKind::Io => "I/O error",
             ErrorKind::KeyInvalid => "invalid cryptographic key",
             ErrorKind::ParseError => "parse error",
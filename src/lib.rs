@@ -133,6 +133,8 @@
     html_root_url = "https://docs.rs/signatory/0.11.2"
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(any(feature = "std", test))]
 #[macro_use]
 extern crate std;
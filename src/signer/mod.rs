@@ -5,6 +5,8 @@ pub(crate) mod digest;
 pub(crate) mod sha2;
 use crate::error::Error;
 use crate::Signature;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
 
 /// Trait for all signers which accept a message (byte slice) and produce a
 /// signature of that message using this signer's private key.
@@ -18,6 +20,27 @@ pub trait Signer<S: Signature>: Send + Sync {
     fn sign(&self, msg: &[u8]) -> Result<S, Error>;
 }
 
+impl<'a, S, T> Signer<S> for &'a T
+where
+    S: Signature,
+    T: Signer<S> + ?Sized,
+{
+    fn sign(&self, msg: &[u8]) -> Result<S, Error> {
+        (**self).sign(msg)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T> Signer<S> for Arc<T>
+where
+    S: Signature,
+    T: Signer<S> + ?Sized,
+{
+    fn sign(&self, msg: &[u8]) -> Result<S, Error> {
+        (**self).sign(msg)
+    }
+}
+
 /// Sign the given message (byte slice) with the given `Signer`, returning a
 /// signature on success.
 #[inline]